@@ -0,0 +1,36 @@
+// Times parsing and each part of every day separately, on the bundled inputs.
+// Run with `cargo bench -p aoc --bench solutions`, adding e.g. `-- day_7/` to only time one day.
+
+use std::hint::black_box;
+
+use common::Solution;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench<S: Solution>(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("day_{}", S::DAY));
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(S::INPUT))));
+
+    let parsed = S::parse(S::INPUT).expect("The bundled input should parse");
+
+    group.bench_function("part_one", |b| b.iter(|| S::part_one(black_box(&parsed))));
+    group.bench_function("part_two", |b| b.iter(|| S::part_two(black_box(&parsed))));
+
+    group.finish();
+}
+
+criterion_group!(
+    solutions,
+    bench::<day_1::Day1>,
+    bench::<day_2::Day2>,
+    bench::<day_3::Day3>,
+    bench::<day_4::Day4>,
+    bench::<day_5::Day5>,
+    bench::<day_6::Day6>,
+    bench::<day_7::Day7>,
+    bench::<day_8::Day8>,
+    bench::<day_9::Day9>,
+    bench::<day_10::Day10>,
+    bench::<day_11::Day11>,
+);
+criterion_main!(solutions);
@@ -0,0 +1,57 @@
+use std::fmt::Display;
+
+use common::{Answers, Part};
+use serde::Serialize;
+
+/// Whether a solved answer matches the confirmed one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Status {
+    Pass,
+    Fail,
+    /// There is no confirmed answer to compare with.
+    Unknown,
+}
+
+impl Status {
+    /// Compares the answer with the confirmed answer of the part.
+    pub fn check(confirmed: &Answers, part: Part, answer: &str) -> Self {
+        match confirmed.get(part) {
+            Some(confirmed) if confirmed == answer => Status::Pass,
+            Some(_) => Status::Fail,
+            None => Status::Unknown,
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => f.pad("PASS"),
+            Status::Fail => f.pad("FAIL"),
+            Status::Unknown => f.pad("UNKNOWN"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_checking_answers() {
+        let answers = Answers::parse("part_1 = 24000\n").unwrap();
+
+        assert_eq!(Status::check(&answers, Part::One, "24000"), Status::Pass);
+        assert_eq!(Status::check(&answers, Part::One, "45000"), Status::Fail);
+        assert_eq!(Status::check(&answers, Part::Two, "45000"), Status::Unknown);
+    }
+
+    #[test]
+    fn test_bundled_answers() {
+        for day in crate::days::all() {
+            let answers = Answers::parse(day.answers).unwrap();
+            assert_ne!(answers, Answers::default(), "Day {}", day.number);
+        }
+    }
+}
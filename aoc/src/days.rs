@@ -0,0 +1,70 @@
+use common::{ParseError, Part, Solution};
+use tracing::info_span;
+
+use crate::measure::{measure, Measurement};
+
+/// A day's [`Solution`] with its types erased, so every day can be kept in one list.
+pub struct Day {
+    pub number: u8,
+    /// The puzzle input bundled with the day.
+    pub input: &'static str,
+    /// The confirmed answers for the bundled input, as TOML.
+    pub answers: &'static str,
+    solve: fn(&str, &[Part]) -> Result<Solved, ParseError>,
+}
+
+/// The answers to a day, with measurements of each phase.
+pub struct Solved {
+    pub parse: Measurement,
+    /// The answer to each part, in the order they were asked for.
+    pub parts: Vec<(String, Measurement)>,
+}
+
+impl Day {
+    fn of<S: Solution>() -> Self {
+        Self {
+            number: S::DAY,
+            input: S::INPUT,
+            answers: S::ANSWERS,
+            solve: solve::<S>,
+        }
+    }
+
+    /// Parses the input once, then solves each of the given parts,
+    /// measuring the parsing and each part separately.
+    pub fn solve(&self, input: &str, parts: &[Part]) -> Result<Solved, ParseError> {
+        (self.solve)(input, parts)
+    }
+}
+
+fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Solved, ParseError> {
+    let (parsed, parse) =
+        measure(|| info_span!("parse", day = S::DAY).in_scope(|| S::parse(input)));
+    let parsed = parsed?;
+
+    let parts = parts
+        .iter()
+        .map(|part| {
+            let span = info_span!("solve", day = S::DAY, part = %part);
+            measure(|| span.in_scope(|| part.solve::<S>(&parsed)))
+        })
+        .collect();
+
+    Ok(Solved { parse, parts })
+}
+
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::of::<day_1::Day1>(),
+        Day::of::<day_2::Day2>(),
+        Day::of::<day_3::Day3>(),
+        Day::of::<day_4::Day4>(),
+        Day::of::<day_5::Day5>(),
+        Day::of::<day_6::Day6>(),
+        Day::of::<day_7::Day7>(),
+        Day::of::<day_8::Day8>(),
+        Day::of::<day_9::Day9>(),
+        Day::of::<day_10::Day10>(),
+        Day::of::<day_11::Day11>(),
+    ]
+}
@@ -0,0 +1,215 @@
+use std::{fs, io, path::Path};
+
+/// What could be found in a saved puzzle description page.
+#[derive(Debug, Default, PartialEq)]
+pub struct Extracted {
+    /// The text of every `<pre><code>` block, in the order they show up.
+    /// The first one is usually the example input.
+    pub blocks: Vec<String>,
+    /// The last emphasized code (like `<code><em>24000</em></code>`) in the
+    /// description of each part, which is where the example's answer is given.
+    pub answers: [Option<String>; 2],
+}
+
+/// Pulls the example blocks and answers out of the HTML of a puzzle page.
+/// Part two's description only shows up on the page once part one is solved.
+pub fn extract(html: &str) -> Extracted {
+    let blocks = between_all(html, "<pre><code>", "</code></pre>")
+        .into_iter()
+        .map(|(_, block)| unescape(&strip_tags(block)))
+        .collect();
+
+    let mut answers = [None, None];
+    let articles = html.split("<article class=\"day-desc\">").skip(1);
+
+    for (answer, article) in answers.iter_mut().zip(articles) {
+        let article = article.split("</article>").next().unwrap_or_default();
+
+        // The puzzles emphasize their answers both ways around.
+        let mut emphasized = between_all(article, "<code><em>", "</em></code>");
+        emphasized.extend(between_all(article, "<em><code>", "</code></em>"));
+
+        *answer = emphasized
+            .into_iter()
+            .max_by_key(|(position, _)| *position)
+            .map(|(_, text)| unescape(&strip_tags(text)));
+    }
+
+    Extracted { blocks, answers }
+}
+
+/// Writes `example.txt` (from the block with the given index) and
+/// `example_answers.toml` into the day's directory. Existing fixtures
+/// are only overwritten when `force` is set.
+pub fn write_fixtures(
+    day_dir: &Path,
+    extracted: &Extracted,
+    block: usize,
+    force: bool,
+) -> io::Result<()> {
+    let example = extracted.blocks.get(block).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!(
+                "there is no block {}, the page only has {}",
+                block + 1,
+                extracted.blocks.len()
+            ),
+        )
+    })?;
+
+    let example_path = day_dir.join("example.txt");
+    let answers_path = day_dir.join("example_answers.toml");
+
+    for path in [&example_path, &answers_path] {
+        if path.exists() && !force {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", path.display()),
+            ));
+        }
+    }
+
+    fs::create_dir_all(day_dir)?;
+    fs::write(example_path, example)?;
+    fs::write(answers_path, answers_toml(&extracted.answers))?;
+
+    Ok(())
+}
+
+/// Writes the answers the same way as `answers.toml`, so numbers stay numbers.
+fn answers_toml(answers: &[Option<String>; 2]) -> String {
+    let mut table = toml::Table::new();
+
+    for (key, answer) in ["part_1", "part_2"].iter().zip(answers) {
+        if let Some(answer) = answer {
+            let value = match answer.parse::<i64>() {
+                Ok(number) => toml::Value::Integer(number),
+                Err(_) => toml::Value::String(answer.clone()),
+            };
+
+            table.insert(key.to_string(), value);
+        }
+    }
+
+    table.to_string()
+}
+
+/// Everything between each `start` and the `end` after it, along with where it starts.
+fn between_all<'a>(text: &'a str, start: &str, end: &str) -> Vec<(usize, &'a str)> {
+    let mut found = Vec::new();
+    let mut offset = 0;
+
+    while let Some(start_index) = text[offset..].find(start) {
+        let inner_start = offset + start_index + start.len();
+
+        let Some(end_index) = text[inner_start..].find(end) else {
+            break;
+        };
+
+        found.push((inner_start, &text[inner_start..inner_start + end_index]));
+        offset = inner_start + end_index + end.len();
+    }
+
+    found
+}
+
+/// Removes tags like the `<em>` used to highlight parts of the examples.
+fn strip_tags(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut in_tag = false;
+
+    for char in text.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => stripped.push(char),
+            _ => (),
+        }
+    }
+
+    stripped
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2>
+<p>For example:</p>
+<pre><code>1000
+2000
+
+<em>3000</em>
+</code></pre>
+<p>The first Elf has <em>3000</em> Calories, so the answer is <code><em>3000</em></code>.</p>
+<pre><code>a &lt; b &amp;&amp; c</code></pre>
+</article>
+<p>Your puzzle answer was <code>71502</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>The total is <em><code>45000</code></em>, not <code><em>1</em></code> like before.</p>
+<p>In this example, the answer is <em><code>45000</code></em>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_extracting_examples() {
+        let extracted = extract(PAGE);
+
+        assert_eq!(extracted.blocks, ["1000\n2000\n\n3000\n", "a < b && c"]);
+        assert_eq!(
+            extracted.answers,
+            [Some("3000".to_string()), Some("45000".to_string())]
+        );
+
+        // A page saved before solving part one.
+        let part_one_only = PAGE.split("<p>Your puzzle answer").next().unwrap();
+        assert_eq!(extract(part_one_only).answers[1], None);
+    }
+
+    #[test]
+    fn test_answers_toml() {
+        assert_eq!(
+            answers_toml(&[Some("24000".to_string()), Some("CMZ".to_string())]),
+            "part_1 = 24000\npart_2 = \"CMZ\"\n"
+        );
+        assert_eq!(answers_toml(&[Some("7".to_string()), None]), "part_1 = 7\n");
+    }
+
+    #[test]
+    fn test_writing_fixtures() {
+        let dir = std::env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let day_dir = dir.join("day_1");
+
+        let extracted = extract(PAGE);
+
+        write_fixtures(&day_dir, &extracted, 0, false).unwrap();
+        assert_eq!(
+            fs::read_to_string(day_dir.join("example.txt")).unwrap(),
+            "1000\n2000\n\n3000\n"
+        );
+
+        let error = write_fixtures(&day_dir, &extracted, 0, false).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+
+        write_fixtures(&day_dir, &extracted, 1, true).unwrap();
+        assert_eq!(
+            fs::read_to_string(day_dir.join("example.txt")).unwrap(),
+            "a < b && c"
+        );
+
+        assert!(write_fixtures(&day_dir, &extracted, 2, true).is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
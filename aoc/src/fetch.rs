@@ -0,0 +1,194 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Where the puzzle inputs get downloaded from, unless told otherwise.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Downloads puzzle inputs on behalf of a logged in user.
+pub struct Fetcher {
+    base_url: String,
+    /// The value of the `session` cookie of a logged in browser.
+    session: String,
+}
+
+/// Whether an input had to be downloaded.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    /// The input was already on disk, so it was left alone.
+    Cached,
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// The server answered, but not with the input.
+    Status {
+        code: u16,
+        body: String,
+    },
+    /// The server could not be reached.
+    Transport(String),
+    Io(io::Error),
+}
+
+impl Fetcher {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+        }
+    }
+
+    pub fn input_url(&self, day: u8) -> String {
+        format!("{}/2022/day/{day}/input", self.base_url)
+    }
+
+    /// Downloads the input of the day.
+    pub fn fetch(&self, day: u8) -> Result<String, FetchError> {
+        let response = ureq::get(&self.input_url(day))
+            .set("Cookie", &format!("session={}", self.session))
+            .set(
+                "User-Agent",
+                "github.com/fekie/AoC-2022 (aoc fetch, run by a user)",
+            )
+            .call();
+
+        match response {
+            Ok(response) => response.into_string().map_err(FetchError::Io),
+            Err(ureq::Error::Status(code, response)) => Err(FetchError::Status {
+                code,
+                body: response.into_string().unwrap_or_default(),
+            }),
+            Err(ureq::Error::Transport(e)) => Err(FetchError::Transport(e.to_string())),
+        }
+    }
+
+    /// Downloads the input of the day to `path`, unless the file already exists.
+    /// Nothing gets written if the download fails.
+    pub fn fetch_to(&self, day: u8, path: &Path) -> Result<Fetched, FetchError> {
+        if path.exists() {
+            return Ok(Fetched::Cached);
+        }
+
+        let input = self.fetch(day)?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(FetchError::Io)?;
+        }
+
+        fs::write(path, input).map_err(FetchError::Io)?;
+
+        Ok(Fetched::Downloaded)
+    }
+}
+
+/// Where the input of the day lives, relative to the root of the repository.
+pub fn input_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("day_{day}/input.txt"))
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::Status { code, body } => {
+                write!(f, "the server answered with status {code}")?;
+
+                // Advent of Code explains what went wrong (like an expired session) in the body.
+                match body.trim() {
+                    "" => Ok(()),
+                    body => write!(f, ": {body}"),
+                }
+            }
+            FetchError::Transport(e) => write!(f, "could not reach the server: {e}"),
+            FetchError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for FetchError {}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use super::*;
+
+    /// Answers a single request with the status and body, handing back the
+    /// request line and headers it got.
+    fn mock_server(status: &'static str, body: &'static str) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+
+            let request = BufReader::new(&stream)
+                .lines()
+                .map(|x| x.unwrap())
+                .take_while(|x| !x.is_empty())
+                .collect::<Vec<String>>();
+
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("day_1").join("input.txt")
+    }
+
+    #[test]
+    fn test_fetching_inputs() {
+        let (base_url, server) = mock_server("200 OK", "1000\n2000\n");
+        let path = temp_path("fetch");
+
+        let fetcher = Fetcher::new(format!("{base_url}/"), "abc123");
+        assert_eq!(fetcher.fetch_to(1, &path).unwrap(), Fetched::Downloaded);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2022/day/1/input HTTP/1.1");
+        assert!(request.iter().any(|x| x == "Cookie: session=abc123"));
+
+        // The mock server is gone, so this would fail if it tried to download again.
+        assert_eq!(fetcher.fetch_to(1, &path).unwrap(), Fetched::Cached);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+
+        fs::remove_dir_all(path.parent().unwrap().parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_failed_fetches() {
+        let (base_url, server) = mock_server("400 Bad Request", "Please log in.");
+        let path = temp_path("failed");
+
+        let fetcher = Fetcher::new(base_url, "expired");
+        let error = fetcher.fetch_to(1, &path).unwrap_err();
+        server.join().unwrap();
+
+        assert!(matches!(error, FetchError::Status { code: 400, .. }));
+        assert_eq!(
+            error.to_string(),
+            "the server answered with status 400: Please log in."
+        );
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,191 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use common::Part;
+use serde::{Serialize, Serializer};
+
+use crate::answers::Status;
+
+/// How the answers get printed.
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum Format {
+    /// A table for reading in the terminal.
+    #[default]
+    Text,
+    /// A JSON array of records.
+    Json,
+    /// CSV with a header line.
+    Csv,
+}
+
+/// The answer to one part of a day.
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub day: u8,
+    #[serde(serialize_with = "serialize_part")]
+    pub part: Part,
+    pub answer: String,
+    /// How the answer compares to the confirmed one.
+    pub status: Status,
+    /// How long solving the part took, not counting parsing. Written out in seconds.
+    #[serde(serialize_with = "serialize_seconds")]
+    pub elapsed: Duration,
+    /// The most bytes of heap solving the part needed at once.
+    pub peak_heap: usize,
+    /// How long parsing the input took. It is shared by both parts of a day.
+    #[serde(serialize_with = "serialize_seconds")]
+    pub parse_elapsed: Duration,
+    /// The most bytes of heap parsing needed at once, including the parsed input.
+    pub parse_peak_heap: usize,
+}
+
+impl Format {
+    pub fn print(&self, records: &[Record]) {
+        match self {
+            Format::Text => print_table(records),
+            Format::Json => println!(
+                "{}",
+                serde_json::to_string_pretty(records).expect("Records are always valid JSON")
+            ),
+            Format::Csv => print_csv(records),
+        }
+    }
+}
+
+fn print_table(records: &[Record]) {
+    // There's nothing worth printing a header for.
+    if records.is_empty() {
+        return;
+    }
+
+    println!(
+        "{:>3}  {:>4}  {:<7}  {:>9}  {:>10}  {:>9}  {:>10}  Answer",
+        "Day", "Part", "Status", "Parse", "Parse Heap", "Solve", "Solve Heap"
+    );
+
+    for record in records {
+        println!("{}", table_row(record));
+    }
+}
+
+/// A record as a row of the table. Some answers (like the CRT in day 10) span
+/// multiple lines, so the extra lines are lined up with the answer column.
+fn table_row(record: &Record) -> String {
+    let mut lines = record.answer.lines();
+    let first_line = lines.next().unwrap_or_default();
+
+    let columns = format!(
+        "{:>3}  {:>4}  {:<7}  {:>9}  {:>10}  {:>9}  {:>10}  ",
+        record.day,
+        record.part,
+        record.status,
+        format!("{:.1?}", record.parse_elapsed),
+        bytes(record.parse_peak_heap),
+        format!("{:.1?}", record.elapsed),
+        bytes(record.peak_heap),
+    );
+    let indent = " ".repeat(columns.chars().count());
+
+    let mut row = format!("{columns}{first_line}");
+    for line in lines {
+        row.push_str(&format!("\n{indent}{line}"));
+    }
+    row
+}
+
+fn print_csv(records: &[Record]) {
+    println!("day,part,answer,status,elapsed,peak_heap,parse_elapsed,parse_peak_heap");
+
+    for record in records {
+        println!(
+            "{},{},{},{},{},{},{},{}",
+            record.day,
+            record.part,
+            csv_field(&record.answer),
+            record.status,
+            record.elapsed.as_secs_f64(),
+            record.peak_heap,
+            record.parse_elapsed.as_secs_f64(),
+            record.parse_peak_heap
+        );
+    }
+}
+
+/// Formats an amount of bytes with a binary unit, like `12.3 KiB`.
+fn bytes(amount: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut size = amount as f64;
+    let mut unit = 0;
+
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    match unit {
+        0 => format!("{amount} B"),
+        _ => format!("{size:.1} {}", UNITS[unit]),
+    }
+}
+
+/// Quotes the field if it has anything in it that would break up the record.
+fn csv_field(field: &str) -> String {
+    match field.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_string(),
+    }
+}
+
+fn serialize_part<S: Serializer>(part: &Part, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8(match part {
+        Part::One => 1,
+        Part::Two => 2,
+    })
+}
+
+fn serialize_seconds<S: Serializer>(elapsed: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(elapsed.as_secs_f64())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_formatting_bytes() {
+        assert_eq!(bytes(0), "0 B");
+        assert_eq!(bytes(1023), "1023 B");
+        assert_eq!(bytes(1536), "1.5 KiB");
+        assert_eq!(bytes(5 * 1024 * 1024), "5.0 MiB");
+    }
+
+    #[test]
+    fn test_table_rows() {
+        let record = Record {
+            day: 10,
+            part: Part::Two,
+            answer: "#..#\n.##.".to_string(),
+            status: Status::Pass,
+            elapsed: Duration::from_micros(1500),
+            peak_heap: 2048,
+            parse_elapsed: Duration::from_micros(20),
+            parse_peak_heap: 100,
+        };
+
+        // The answer column starts after the 66 characters the header puts before `Answer`.
+        let row = table_row(&record);
+        let lines = row.lines().collect::<Vec<&str>>();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].chars().count(), 66 + 4);
+        assert!(lines[0].ends_with("#..#"));
+        assert_eq!(lines[1], format!("{:66}.##.", ""));
+    }
+
+    #[test]
+    fn test_csv_fields() {
+        assert_eq!(csv_field("71502"), "71502");
+        assert_eq!(csv_field("#..#\n.##."), "\"#..#\n.##.\"");
+        assert_eq!(csv_field("a,\"b\""), "\"a,\"\"b\"\"\"");
+    }
+}
@@ -0,0 +1,444 @@
+use std::collections::HashSet;
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// Makes up valid inputs for a day, for stress testing and benchmarking.
+pub struct Generator {
+    pub day: u8,
+    /// What the size of an input counts.
+    pub unit: &'static str,
+    /// About as big as the real input.
+    pub default_size: usize,
+    generate: fn(&mut StdRng, usize) -> String,
+}
+
+impl Generator {
+    /// The same seed and size always make the same input.
+    pub fn generate(&self, size: usize, seed: u64) -> String {
+        (self.generate)(&mut StdRng::seed_from_u64(seed), size)
+    }
+}
+
+pub fn all() -> Vec<Generator> {
+    vec![
+        Generator {
+            day: 1,
+            unit: "elves",
+            default_size: 250,
+            generate: calories,
+        },
+        Generator {
+            day: 2,
+            unit: "rounds",
+            default_size: 2500,
+            generate: strategy_guide,
+        },
+        Generator {
+            day: 3,
+            unit: "rucksacks, rounded up to whole groups",
+            default_size: 300,
+            generate: rucksacks,
+        },
+        Generator {
+            day: 4,
+            unit: "pairs",
+            default_size: 1000,
+            generate: section_assignments,
+        },
+        Generator {
+            day: 5,
+            unit: "moves",
+            default_size: 500,
+            generate: crates,
+        },
+        Generator {
+            day: 6,
+            unit: "characters",
+            default_size: 4096,
+            generate: datastream,
+        },
+        Generator {
+            day: 7,
+            unit: "directories",
+            default_size: 185,
+            generate: terminal,
+        },
+        Generator {
+            day: 8,
+            unit: "trees along each side",
+            default_size: 99,
+            generate: trees,
+        },
+        Generator {
+            day: 9,
+            unit: "motions",
+            default_size: 2000,
+            generate: motions,
+        },
+        Generator {
+            day: 10,
+            unit: "instructions, or enough to draw the screen",
+            default_size: 140,
+            generate: program,
+        },
+        Generator {
+            day: 11,
+            unit: "items",
+            default_size: 36,
+            generate: monkeys,
+        },
+    ]
+}
+
+/// Lines of calories, with a blank line after each elf.
+fn calories(rng: &mut StdRng, elves: usize) -> String {
+    let mut text = String::new();
+
+    for i in 0..elves {
+        if i > 0 {
+            text.push('\n');
+        }
+
+        for _ in 0..rng.gen_range(1..=15) {
+            text += &format!("{}\n", rng.gen_range(1000..=60000));
+        }
+    }
+
+    text
+}
+
+fn strategy_guide(rng: &mut StdRng, rounds: usize) -> String {
+    (0..rounds)
+        .map(|_| {
+            let enemy = *b"ABC".choose(rng).unwrap() as char;
+            let user = *b"XYZ".choose(rng).unwrap() as char;
+            format!("{enemy} {user}\n")
+        })
+        .collect()
+}
+
+/// Rucksacks in groups of 3, where the compartments of each rucksack have
+/// exactly one item in common, and so do the rucksacks of each group.
+fn rucksacks(rng: &mut StdRng, rucksacks: usize) -> String {
+    let items = ('a'..='z').chain('A'..='Z').collect::<Vec<char>>();
+    let mut text = String::new();
+
+    for _ in 0..rucksacks.div_ceil(3) {
+        let mut items = items.clone();
+        items.shuffle(rng);
+        let (badge, others) = items.split_first().unwrap();
+
+        for k in 0..3 {
+            // Every other item is left out of one of the rucksacks, so only the badge is in all of them.
+            let mut pool = others
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| i % 3 != k)
+                .map(|(_, &x)| x)
+                .collect::<Vec<char>>();
+            pool.shuffle(rng);
+
+            let common = pool.pop().unwrap();
+            let (first_pool, second_pool) = pool.split_at(pool.len() / 2);
+
+            let len = rng.gen_range(4..=16);
+            let mut first = vec![common];
+            let mut second = vec![common];
+            first.extend((1..len).map(|_| *first_pool.choose(rng).unwrap()));
+            second.extend((1..len).map(|_| *second_pool.choose(rng).unwrap()));
+
+            // The badge replaces an item other than the common one.
+            match rng.gen_bool(0.5) {
+                true => first[1] = *badge,
+                false => second[1] = *badge,
+            }
+
+            first.shuffle(rng);
+            second.shuffle(rng);
+
+            text += &format!(
+                "{}{}\n",
+                first.iter().collect::<String>(),
+                second.iter().collect::<String>()
+            );
+        }
+    }
+
+    text
+}
+
+fn section_assignments(rng: &mut StdRng, pairs: usize) -> String {
+    let assignment = |rng: &mut StdRng| {
+        let start = rng.gen_range(1..=99);
+        let end = rng.gen_range(start..=99);
+        format!("{start}-{end}")
+    };
+
+    (0..pairs)
+        .map(|_| format!("{},{}\n", assignment(rng), assignment(rng)))
+        .collect()
+}
+
+/// A drawing of 9 stacks, followed by moves that never take more crates
+/// than a stack holds.
+fn crates(rng: &mut StdRng, moves: usize) -> String {
+    let mut stacks = (0..9)
+        .map(|_| {
+            (0..rng.gen_range(1..=8))
+                .map(|_| rng.gen_range('A'..='Z'))
+                .collect::<Vec<char>>()
+        })
+        .collect::<Vec<Vec<char>>>();
+
+    let height = stacks.iter().map(|x| x.len()).max().unwrap();
+    let mut text = String::new();
+
+    for level in (0..height).rev() {
+        let row = stacks
+            .iter()
+            .map(|x| match x.get(level) {
+                Some(c) => format!("[{c}]"),
+                None => "   ".to_string(),
+            })
+            .collect::<Vec<String>>();
+
+        text += &format!("{}\n", row.join(" "));
+    }
+
+    let numbers = (1..=stacks.len())
+        .map(|x| format!(" {x} "))
+        .collect::<Vec<String>>();
+
+    text += &format!("{}\n\n", numbers.join(" "));
+
+    for _ in 0..moves {
+        let from = loop {
+            let from = rng.gen_range(0..stacks.len());
+
+            if !stacks[from].is_empty() {
+                break from;
+            }
+        };
+
+        let to = (from + rng.gen_range(1..stacks.len())) % stacks.len();
+        let amount = rng.gen_range(1..=stacks[from].len().min(10));
+
+        // Keep track of the heights, so later moves stay valid too.
+        let left = stacks[from].len() - amount;
+        let moved = stacks[from].split_off(left);
+        stacks[to].extend(moved);
+
+        text += &format!("move {amount} from {} to {}\n", from + 1, to + 1);
+    }
+
+    text
+}
+
+/// Letters that can't hold a start-of-message marker, apart from the one
+/// put somewhere along the way.
+fn datastream(rng: &mut StdRng, len: usize) -> String {
+    let len = len.max(14);
+
+    // With only 10 letters to pick from, 14 different ones in a row can't happen by chance.
+    let mut chars = (0..len)
+        .map(|_| rng.gen_range('a'..='j'))
+        .collect::<Vec<char>>();
+
+    let mut marker = ('a'..='z').collect::<Vec<char>>();
+    marker.shuffle(rng);
+
+    let start = rng.gen_range(0..=len - 14);
+    chars[start..start + 14].copy_from_slice(&marker[..14]);
+
+    chars.into_iter().chain(['\n']).collect()
+}
+
+/// The terminal output of walking a random tree of directories depth first,
+/// listing each one along the way.
+fn terminal(rng: &mut StdRng, directories: usize) -> String {
+    let directories = directories.max(1);
+
+    let mut names = vec![String::from("/")];
+    let mut children = vec![Vec::new()];
+
+    for i in 1..directories {
+        let parent = rng.gen_range(0..i);
+
+        let name = loop {
+            let name = (0..rng.gen_range(1..=8))
+                .map(|_| rng.gen_range('a'..='z'))
+                .collect::<String>();
+
+            if !children[parent].iter().any(|&x: &usize| names[x] == name) {
+                break name;
+            }
+        };
+
+        names.push(name);
+        children.push(Vec::new());
+        children[parent].push(i);
+    }
+
+    // There are 2 files in each directory on average, so this fills about 45000000
+    // of the 70000000 on the disk, like the real input does.
+    let max_file_size = (45_000_000 / directories).clamp(1, 300_000);
+
+    let mut text = String::new();
+    // Directories still to visit, and `None` for going back up.
+    let mut stack = vec![Some(0)];
+
+    while let Some(entry) = stack.pop() {
+        let Some(directory) = entry else {
+            text += "$ cd ..\n";
+            continue;
+        };
+
+        text += &format!("$ cd {}\n$ ls\n", names[directory]);
+
+        for &child in &children[directory] {
+            text += &format!("dir {}\n", names[child]);
+        }
+
+        let mut files = HashSet::new();
+        for _ in 0..rng.gen_range(0..=4) {
+            let name = (0..rng.gen_range(1..=8))
+                .map(|_| rng.gen_range('a'..='z'))
+                .collect::<String>();
+            let extension = ["", ".txt", ".dat", ".log"].choose(rng).unwrap();
+
+            // Files can't share a name with each other, or with a directory.
+            let name = format!("{name}{extension}");
+            if children[directory].iter().any(|&x| names[x] == name) || !files.insert(name.clone())
+            {
+                continue;
+            }
+
+            text += &format!("{} {name}\n", rng.gen_range(1..=max_file_size));
+        }
+
+        for &child in children[directory].iter().rev() {
+            stack.push(None);
+            stack.push(Some(child));
+        }
+    }
+
+    text
+}
+
+fn trees(rng: &mut StdRng, side: usize) -> String {
+    (0..side.max(1))
+        .map(|_| {
+            let row = (0..side.max(1))
+                .map(|_| char::from_digit(rng.gen_range(0..=9), 10).unwrap())
+                .collect::<String>();
+            format!("{row}\n")
+        })
+        .collect()
+}
+
+fn motions(rng: &mut StdRng, motions: usize) -> String {
+    (0..motions)
+        .map(|_| {
+            let direction = *b"UDLR".choose(rng).unwrap() as char;
+            format!("{direction} {}\n", rng.gen_range(1..=19))
+        })
+        .collect()
+}
+
+/// A program that runs for at least the 240 cycles it takes to draw the screen.
+fn program(rng: &mut StdRng, instructions: usize) -> String {
+    let mut text = String::new();
+    let mut cycles = 0;
+
+    for i in 0.. {
+        if i >= instructions && cycles >= 240 {
+            break;
+        }
+
+        match rng.gen_bool(0.7) {
+            true => {
+                text += &format!("addx {}\n", rng.gen_range(-20..=20));
+                cycles += 2;
+            }
+            false => {
+                text += "noop\n";
+                cycles += 1;
+            }
+        }
+    }
+
+    text
+}
+
+/// Notes on 8 monkeys, with the items spread between them. Each monkey tests
+/// a different prime, which keeps the worry levels small enough not to overflow.
+fn monkeys(rng: &mut StdRng, items: usize) -> String {
+    let mut divisors = [2, 3, 5, 7, 11, 13, 17, 19];
+    divisors.shuffle(rng);
+
+    let mut held = vec![Vec::new(); divisors.len()];
+    for _ in 0..items {
+        held[rng.gen_range(0..divisors.len())].push(rng.gen_range(50..=99).to_string());
+    }
+
+    let mut notes = Vec::new();
+
+    for (i, (divisor, items)) in divisors.iter().zip(held).enumerate() {
+        let items = match items.is_empty() {
+            true => String::new(),
+            false => format!(" {}", items.join(", ")),
+        };
+
+        let operation = match rng.gen_range(0..10) {
+            0 => "* old".to_string(),
+            1..=3 => format!("* {}", rng.gen_range(2..=19)),
+            _ => format!("+ {}", rng.gen_range(1..=8)),
+        };
+
+        let mut other = || (i + rng.gen_range(1..divisors.len())) % divisors.len();
+        let (on_success, on_fail) = (other(), other());
+
+        notes.push(format!(
+            "Monkey {i}:\n  Starting items:{items}\n  Operation: new = old {operation}\n  \
+             Test: divisible by {divisor}\n    If true: throw to monkey {on_success}\n    \
+             If false: throw to monkey {on_fail}\n"
+        ));
+    }
+
+    notes.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use common::Part;
+
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        for (day, generator) in days::all().iter().zip(all()) {
+            assert_eq!(day.number, generator.day);
+
+            for (size, seed) in [(0, 0), (1, 1), (30, 2), (generator.default_size, 3)] {
+                let input = generator.generate(size, seed);
+
+                if let Err(e) = day.solve(&input, &Part::BOTH) {
+                    panic!(
+                        "Day {} can't parse a generated input: {e}\n{input}",
+                        day.number
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_seeds() {
+        let generator = &all()[0];
+
+        assert_eq!(generator.generate(10, 7), generator.generate(10, 7));
+        assert_ne!(generator.generate(10, 7), generator.generate(10, 8));
+        assert_eq!(generator.generate(3, 0).split("\n\n").count(), 3);
+    }
+}
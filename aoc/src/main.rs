@@ -0,0 +1,410 @@
+// Runs the solutions for every day from a single binary.
+
+mod answers;
+mod days;
+mod examples;
+mod fetch;
+mod format;
+mod generate;
+mod measure;
+mod repl;
+
+use std::{io::IsTerminal, path::PathBuf, str::FromStr};
+
+use answers::Status;
+use clap::{Parser, Subcommand};
+use common::{Answers, Input, Part};
+use days::Day;
+use fetch::{FetchError, Fetched, Fetcher};
+use format::{Format, Record};
+use measure::CountingAllocator;
+use rayon::prelude::*;
+use tracing_subscriber::EnvFilter;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves a day, or every day.
+    Run {
+        /// The day to solve (1-11), or `all`.
+        day: Selection,
+
+        /// Only solve this part.
+        #[arg(long)]
+        part: Option<Part>,
+
+        /// Read the puzzle input from this file (or `-` for stdin) instead
+        /// of the bundled one. Only works when solving a single day.
+        #[arg(long)]
+        input: Option<Input>,
+
+        /// How to print the answers.
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+
+        /// How many days to solve at once. Defaults to the number of CPUs.
+        #[arg(long)]
+        jobs: Option<usize>,
+
+        /// Print what the solutions are doing to stderr. Takes a level (`info`,
+        /// `debug` or `trace`), or directives like `day_9=trace,day_11=debug`.
+        #[arg(long, value_name = "FILTER", num_args = 0..=1, default_missing_value = "debug")]
+        trace: Option<String>,
+    },
+    /// Downloads the input of a day (or every day) to `day_N/input.txt`,
+    /// unless it is already there.
+    Fetch {
+        /// The day to download (1-11), or `all`.
+        day: Selection,
+
+        /// The `session` cookie of a browser logged in to Advent of Code.
+        #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+        session: String,
+
+        /// Where to download the inputs from.
+        #[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+        base_url: String,
+
+        /// The root of the repository, which holds the `day_N` directories.
+        #[arg(long, default_value = ".")]
+        dir: PathBuf,
+    },
+    /// Writes the example from a saved puzzle page to `day_N/example.txt`,
+    /// and the answers given for it to `day_N/example_answers.toml`.
+    Examples {
+        /// The day the page is for.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..))]
+        day: u8,
+
+        /// The puzzle page, saved as HTML.
+        page: PathBuf,
+
+        /// Which of the page's code blocks is the example, counting from 1.
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..))]
+        block: u8,
+
+        /// The root of the repository, which holds the `day_N` directories.
+        #[arg(long, default_value = ".")]
+        dir: PathBuf,
+
+        /// Overwrite fixtures that are already there.
+        #[arg(long)]
+        force: bool,
+    },
+    /// Parses the input of a day, then answers questions about it typed in
+    /// one per line. Type `help` to see what can be asked.
+    Repl {
+        /// The day to explore (5, 7, 8 or 11).
+        day: u8,
+
+        /// Explore this file instead of the bundled input.
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Makes up a valid input for a day, to stress test or benchmark the solution with.
+    Gen {
+        /// The day to make an input for.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=11))]
+        day: u8,
+
+        /// How big the input should be, in elves, rounds, moves and so on depending
+        /// on the day. Defaults to about the size of the real input.
+        #[arg(long)]
+        size: Option<usize>,
+
+        /// The same seed (and size) always makes the same input.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Write the input to this file instead of stdout.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Selection {
+    All,
+    Day(u8),
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            jobs,
+            trace,
+        } => {
+            if matches!(day, Selection::All) && input.is_some() {
+                eprintln!("An input can only be given when solving a single day.");
+                std::process::exit(1);
+            }
+
+            if let Some(filter) = trace {
+                let filter = match EnvFilter::try_new(&filter) {
+                    Ok(filter) => filter,
+                    Err(e) => {
+                        eprintln!("`{filter}` is not a valid trace filter: {e}");
+                        std::process::exit(1);
+                    }
+                };
+
+                tracing_subscriber::fmt()
+                    .with_env_filter(filter)
+                    .with_writer(std::io::stderr)
+                    .with_ansi(std::io::stderr().is_terminal())
+                    .init();
+            }
+
+            let input = input.unwrap_or_default();
+
+            let parts = match part {
+                Some(part) => vec![part],
+                None => Part::BOTH.to_vec(),
+            };
+
+            if let Some(jobs) = jobs {
+                rayon::ThreadPoolBuilder::new()
+                    .num_threads(jobs)
+                    .build_global()
+                    .expect("The thread pool is only built once");
+            }
+
+            // Each day is solved on the thread pool, but collecting keeps them in day order.
+            let outcomes = days::all()
+                .par_iter()
+                .filter(|x| day.includes(x.number))
+                .map(|day| run_day(day, &input, &parts))
+                .collect::<Vec<Result<Vec<Record>, String>>>();
+
+            let mut records = Vec::new();
+            let mut failed = false;
+
+            for outcome in outcomes {
+                match outcome {
+                    Ok(day_records) => records.extend(day_records),
+                    Err(e) => {
+                        eprintln!("{e}");
+                        failed = true;
+                    }
+                }
+            }
+
+            if records.iter().any(|x| x.status == Status::Fail) {
+                failed = true;
+            }
+
+            format.print(&records);
+
+            if failed {
+                std::process::exit(1);
+            }
+        }
+        Command::Fetch {
+            day,
+            session,
+            base_url,
+            dir,
+        } => {
+            let fetcher = Fetcher::new(base_url, session);
+            let mut failed = false;
+
+            for day in days::all().iter().filter(|x| day.includes(x.number)) {
+                let path = dir.join(fetch::input_path(day.number));
+
+                match fetcher.fetch_to(day.number, &path) {
+                    Ok(Fetched::Downloaded) => {
+                        println!("Day {}: downloaded to {}", day.number, path.display())
+                    }
+                    Ok(Fetched::Cached) => {
+                        println!("Day {}: already at {}", day.number, path.display())
+                    }
+                    Err(e) => {
+                        eprintln!("Day {}: could not download the input: {e}", day.number);
+                        failed = true;
+
+                        // Every other day would fail the same way.
+                        if let FetchError::Status { code: 400, .. } = e {
+                            break;
+                        }
+                    }
+                }
+            }
+
+            if failed {
+                std::process::exit(1);
+            }
+        }
+        Command::Examples {
+            day,
+            page,
+            block,
+            dir,
+            force,
+        } => {
+            let html = match std::fs::read_to_string(&page) {
+                Ok(html) => html,
+                Err(e) => {
+                    eprintln!("Could not read {}: {e}", page.display());
+                    std::process::exit(1);
+                }
+            };
+
+            let extracted = examples::extract(&html);
+
+            for (i, text) in extracted.blocks.iter().enumerate() {
+                println!(
+                    "Block {}: {} lines, starting with {:?}",
+                    i + 1,
+                    text.lines().count(),
+                    text.lines().next().unwrap_or_default()
+                );
+            }
+
+            for (part, answer) in Part::BOTH.iter().zip(&extracted.answers) {
+                match answer {
+                    Some(answer) => println!("Part {part} answer: {answer}"),
+                    None => println!("Part {part} answer: not found"),
+                }
+            }
+
+            let day_dir = dir.join(format!("day_{day}"));
+
+            if let Err(e) =
+                examples::write_fixtures(&day_dir, &extracted, usize::from(block) - 1, force)
+            {
+                eprintln!("Could not write the fixtures: {e}");
+                std::process::exit(1);
+            }
+
+            println!("Wrote the fixtures to {}", day_dir.display());
+        }
+        Command::Repl { day, input } => {
+            let Some(bundled) = days::all().into_iter().find(|x| x.number == day) else {
+                eprintln!("There is no day {day}.");
+                std::process::exit(1);
+            };
+
+            let input = match input {
+                Some(path) => Input::Path(path),
+                None => Input::Bundled,
+            };
+
+            let day_input = match input.read(bundled.input) {
+                Ok(day_input) => day_input,
+                Err(e) => {
+                    eprintln!("Could not read the puzzle input: {e}");
+                    std::process::exit(1);
+                }
+            };
+
+            if let Err(e) = repl::run(day, &day_input, std::io::stdin().lock(), std::io::stdout()) {
+                eprintln!("Could not explore the input: {e}");
+                std::process::exit(1);
+            }
+        }
+        Command::Gen {
+            day,
+            size,
+            seed,
+            output,
+        } => {
+            let generator = generate::all()
+                .into_iter()
+                .find(|x| x.day == day)
+                .expect("Every day has a generator");
+
+            let size = size.unwrap_or(generator.default_size);
+            let input = generator.generate(size, seed);
+
+            match output {
+                Some(path) => {
+                    if let Err(e) = std::fs::write(&path, input) {
+                        eprintln!("Could not write {}: {e}", path.display());
+                        std::process::exit(1);
+                    }
+
+                    eprintln!(
+                        "Wrote day {day}'s input with {size} {} to {}",
+                        generator.unit,
+                        path.display()
+                    );
+                }
+                None => print!("{input}"),
+            }
+        }
+    }
+}
+
+/// Solves the parts of a single day, or describes why it couldn't be.
+fn run_day(day: &Day, input: &Input, parts: &[Part]) -> Result<Vec<Record>, String> {
+    let day_input = input
+        .read(day.input)
+        .map_err(|e| format!("Could not read the puzzle input: {e}"))?;
+
+    // The confirmed answers only hold for the bundled input.
+    let confirmed = match input {
+        Input::Bundled => Answers::parse(day.answers)
+            .unwrap_or_else(|e| panic!("Day {} has an invalid answers.toml: {e}", day.number)),
+        _ => Answers::default(),
+    };
+
+    let solved = day
+        .solve(&day_input, parts)
+        .map_err(|e| format!("Day {}: could not parse the puzzle input: {e}", day.number))?;
+
+    let records = parts
+        .iter()
+        .zip(solved.parts)
+        .map(|(part, (answer, measurement))| Record {
+            day: day.number,
+            part: *part,
+            status: Status::check(&confirmed, *part, &answer),
+            answer,
+            elapsed: measurement.elapsed,
+            peak_heap: measurement.peak_heap,
+            parse_elapsed: solved.parse.elapsed,
+            parse_peak_heap: solved.parse.peak_heap,
+        })
+        .collect();
+
+    Ok(records)
+}
+
+impl Selection {
+    fn includes(&self, day: u8) -> bool {
+        match self {
+            Selection::All => true,
+            Selection::Day(selected) => *selected == day,
+        }
+    }
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Selection::All);
+        }
+
+        match s.parse::<u8>() {
+            Ok(day) if (1..=11).contains(&day) => Ok(Selection::Day(day)),
+            _ => Err(format!("`{s}` is not a day between 1 and 11, or `all`")),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    time::{Duration, Instant},
+};
+
+/// Wraps the system allocator, keeping count of how much of the heap is in use.
+/// The counts are kept per thread, so days being solved at the same time
+/// don't show up in each other's measurements.
+pub struct CountingAllocator;
+
+thread_local! {
+    // These are signed since memory can be freed on a different thread than
+    // the one that allocated it, which would take a thread's count below 0.
+
+    /// The bytes currently allocated by this thread.
+    static CURRENT: Cell<isize> = const { Cell::new(0) };
+    /// The most bytes this thread had allocated at once since the last [`measure`] started.
+    static PEAK: Cell<isize> = const { Cell::new(0) };
+}
+
+/// How long something took, and how much of the heap it needed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Measurement {
+    pub elapsed: Duration,
+    /// The most bytes it had allocated at once, on top of what was
+    /// already allocated when it started.
+    pub peak_heap: usize,
+}
+
+/// Runs `f`, measuring its wall-clock time and peak heap usage.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Measurement) {
+    let baseline = CURRENT.get();
+    PEAK.set(baseline);
+
+    let start = Instant::now();
+    let value = f();
+    let elapsed = start.elapsed();
+
+    let peak_heap = (PEAK.get() - baseline).max(0) as usize;
+
+    (value, Measurement { elapsed, peak_heap })
+}
+
+fn grow(bytes: usize) {
+    let current = CURRENT.get() + bytes as isize;
+    CURRENT.set(current);
+    PEAK.set(PEAK.get().max(current));
+}
+
+fn shrink(bytes: usize) {
+    CURRENT.set(CURRENT.get() - bytes as isize);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            match new_size > layout.size() {
+                true => grow(new_size - layout.size()),
+                false => shrink(layout.size() - new_size),
+            }
+        }
+        new_ptr
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_measuring_heap() {
+        let (vec, measurement) = measure(|| {
+            let scratch = vec![0u8; 4096];
+            drop(scratch);
+            vec![0u8; 1024]
+        });
+
+        assert_eq!(vec.len(), 1024);
+        assert_eq!(measurement.peak_heap, 4096);
+    }
+}
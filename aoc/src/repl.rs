@@ -0,0 +1,358 @@
+use std::io::{self, BufRead, Write};
+
+use common::{ParseError, Part, Solution};
+use day_11::{Monkey, Operation};
+use day_5::{Dock, Instruction};
+use day_7::{FileSystem, INodeDetails};
+use day_8::Trees;
+use grid::Point;
+
+/// A parsed input that can answer questions about itself.
+pub trait Explore {
+    /// The queries it answers and what they take, to list in `help`.
+    const QUERIES: &'static [(&'static str, &'static str)];
+
+    /// Answers a query, or explains why it can't be.
+    fn query(&self, name: &str, args: &[&str]) -> Result<String, String>;
+}
+
+/// The days that have something to explore.
+pub const DAYS: [u8; 4] = [5, 7, 8, 11];
+
+/// Parses the input of the day, then answers queries read from `commands`
+/// until they run out or `quit` is given.
+pub fn run(
+    day: u8,
+    input: &str,
+    commands: impl BufRead,
+    output: impl Write,
+) -> Result<(), ReplError> {
+    match day {
+        5 => session::<day_5::Day5>(input, commands, output),
+        7 => session::<day_7::Day7>(input, commands, output),
+        8 => session::<day_8::Day8>(input, commands, output),
+        11 => session::<day_11::Day11>(input, commands, output),
+        _ => Err(ReplError::Unsupported(day)),
+    }
+}
+
+#[derive(Debug)]
+pub enum ReplError {
+    /// The day has nothing to explore.
+    Unsupported(u8),
+    Parse(ParseError),
+    Io(io::Error),
+}
+
+fn session<S: Solution>(
+    input: &str,
+    commands: impl BufRead,
+    mut output: impl Write,
+) -> Result<(), ReplError>
+where
+    S::Parsed: Explore,
+{
+    let parsed = S::parse(input).map_err(ReplError::Parse)?;
+    let mut lines = commands.lines();
+
+    loop {
+        write!(output, "day {}> ", S::DAY).map_err(ReplError::Io)?;
+        output.flush().map_err(ReplError::Io)?;
+
+        let Some(line) = lines.next() else {
+            writeln!(output).map_err(ReplError::Io)?;
+            return Ok(());
+        };
+
+        let line = line.map_err(ReplError::Io)?;
+        let words = line.split_whitespace().collect::<Vec<&str>>();
+
+        let Some((&name, args)) = words.split_first() else {
+            continue;
+        };
+
+        let answer = match name {
+            "quit" | "exit" => return Ok(()),
+            "help" => Ok(help(S::Parsed::QUERIES)),
+            "part" => match args {
+                [part] => part.parse::<Part>().map(|x| x.solve::<S>(&parsed)),
+                _ => Err("expected `part 1` or `part 2`".to_string()),
+            },
+            _ => parsed.query(name, args),
+        };
+
+        match answer {
+            Ok(answer) => writeln!(output, "{answer}"),
+            Err(e) => writeln!(output, "error: {e}"),
+        }
+        .map_err(ReplError::Io)?;
+    }
+}
+
+fn help(queries: &[(&str, &str)]) -> String {
+    let general = [
+        ("part 1|2", "solves a part of the puzzle"),
+        ("help", "lists the queries"),
+        ("quit", "stops exploring"),
+    ];
+
+    queries
+        .iter()
+        .chain(&general)
+        .map(|(usage, about)| format!("{usage:<12} {about}"))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Parses the argument at the index as a number.
+fn number<T: std::str::FromStr>(args: &[&str], index: usize, what: &str) -> Result<T, String> {
+    let arg = args.get(index).ok_or_else(|| format!("expected {what}"))?;
+
+    arg.parse::<T>()
+        .map_err(|_| format!("`{arg}` is not {what}"))
+}
+
+impl Explore for (Dock, Vec<Instruction>) {
+    const QUERIES: &'static [(&'static str, &'static str)] = &[(
+        "stack N",
+        "the crates in a stack before any moves, bottom first",
+    )];
+
+    fn query(&self, name: &str, args: &[&str]) -> Result<String, String> {
+        let (dock, _) = self;
+
+        match name {
+            "stack" => {
+                let stack_number = number::<usize>(args, 0, "a stack number")?;
+
+                let stack = stack_number
+                    .checked_sub(1)
+                    .and_then(|x| dock.stacks.get(x))
+                    .ok_or_else(|| {
+                        format!(
+                            "there is no stack {stack_number}, expected 1 to {}",
+                            dock.stacks.len()
+                        )
+                    })?;
+
+                Ok(match stack.is_empty() {
+                    true => "empty".to_string(),
+                    false => stack
+                        .iter()
+                        .map(|c| format!("[{c}]"))
+                        .collect::<Vec<String>>()
+                        .join(" "),
+                })
+            }
+            _ => Err(format!("unknown query `{name}`, try `help`")),
+        }
+    }
+}
+
+impl Explore for FileSystem {
+    const QUERIES: &'static [(&'static str, &'static str)] =
+        &[("du PATH", "the total size of a file or directory")];
+
+    fn query(&self, name: &str, args: &[&str]) -> Result<String, String> {
+        match name {
+            "du" => {
+                let path = args.first().ok_or("expected a path like `/a/e`")?;
+
+                // Every part of the paths in the file system ends with a `/`.
+                let full_path = match path.trim_matches('/') {
+                    "" => "/".to_string(),
+                    trimmed => format!("/{trimmed}/"),
+                };
+
+                let id = self
+                    .id_from_path(&full_path)
+                    .ok_or_else(|| format!("nothing was listed at `{path}`"))?;
+
+                let kind = match self.inode_from_id(id).data {
+                    INodeDetails::Directory { .. } => "directory",
+                    INodeDetails::File { .. } => "file",
+                };
+
+                Ok(format!("{}\t{path} ({kind})", self.size_of_inode(id)))
+            }
+            _ => Err(format!("unknown query `{name}`, try `help`")),
+        }
+    }
+}
+
+impl Explore for Trees {
+    const QUERIES: &'static [(&'static str, &'static str)] = &[(
+        "tree X Y",
+        "the tree in column X of row Y, counting from 0 at the top left",
+    )];
+
+    fn query(&self, name: &str, args: &[&str]) -> Result<String, String> {
+        match name {
+            "tree" => {
+                let column = number::<i64>(args, 0, "a column")?;
+                let row = number::<i64>(args, 1, "a row")?;
+
+                // The rows are counted the way they are in the input, but the grid starts at the bottom.
+                let point = Point::new(column, self.grid.height() as i64 - 1 - row);
+
+                let height = self.grid.get(point).ok_or_else(|| {
+                    format!(
+                        "there is no tree at {column} {row}, the grid is {} wide and {} tall",
+                        self.grid.width(),
+                        self.grid.height()
+                    )
+                })?;
+
+                let visibility = match self.is_visible(point) {
+                    true => "visible",
+                    false => "hidden",
+                };
+
+                Ok(format!(
+                    "height {height}, {visibility}, scenic score {}",
+                    self.scenic_score(point)
+                ))
+            }
+            _ => Err(format!("unknown query `{name}`, try `help`")),
+        }
+    }
+}
+
+impl Explore for Vec<Monkey> {
+    const QUERIES: &'static [(&'static str, &'static str)] =
+        &[("monkey N", "what a monkey starts with and does")];
+
+    fn query(&self, name: &str, args: &[&str]) -> Result<String, String> {
+        match name {
+            "monkey" => {
+                let index = number::<usize>(args, 0, "a monkey number")?;
+
+                let monkey = self.get(index).ok_or_else(|| {
+                    format!(
+                        "there is no monkey {index}, expected 0 to {}",
+                        self.len() - 1
+                    )
+                })?;
+
+                let items = monkey
+                    .items
+                    .borrow()
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                let operation = match monkey.operation {
+                    Operation::Add(Some(n)) => format!("old + {n}"),
+                    Operation::Add(None) => "old + old".to_string(),
+                    Operation::Multiply(Some(n)) => format!("old * {n}"),
+                    Operation::Multiply(None) => "old * old".to_string(),
+                };
+
+                Ok(format!(
+                    "items: {items}\nworry: new = {operation}\n\
+                     divisible by {}: throw to monkey {}\notherwise: throw to monkey {}",
+                    monkey.divisible_by, monkey.on_success, monkey.on_fail
+                ))
+            }
+            _ => Err(format!("unknown query `{name}`, try `help`")),
+        }
+    }
+}
+
+impl std::fmt::Display for ReplError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplError::Unsupported(day) => write!(
+                f,
+                "day {day} has nothing to explore, try one of days {}",
+                DAYS.map(|x| x.to_string()).join(", ")
+            ),
+            ReplError::Parse(e) => write!(f, "could not parse the puzzle input: {e}"),
+            ReplError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for ReplError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Runs the commands, returning what was answered without the prompts.
+    fn answers(day: u8, input: &str, commands: &str) -> Vec<String> {
+        let mut output = Vec::new();
+        run(day, input, commands.as_bytes(), &mut output).unwrap();
+
+        String::from_utf8(output)
+            .unwrap()
+            .split(&format!("day {day}> "))
+            .map(|x| x.trim_end().to_string())
+            .filter(|x| !x.is_empty())
+            .collect()
+    }
+
+    #[test]
+    fn test_queries() {
+        assert_eq!(
+            answers(
+                5,
+                include_str!("../../day_5/example.txt"),
+                "stack 2\nstack 4\n"
+            ),
+            ["[M] [C] [D]", "error: there is no stack 4, expected 1 to 3"]
+        );
+
+        assert_eq!(
+            answers(
+                7,
+                include_str!("../../day_7/example.txt"),
+                "du /a/e\ndu /\ndu /x"
+            ),
+            [
+                "584\t/a/e (directory)",
+                "48381165\t/ (directory)",
+                "error: nothing was listed at `/x`"
+            ]
+        );
+
+        assert_eq!(
+            answers(
+                8,
+                include_str!("../../day_8/example.txt"),
+                "tree 2 3\ntree 1 1\ntree 5"
+            ),
+            [
+                "height 5, visible, scenic score 8",
+                "height 5, visible, scenic score 1",
+                "error: expected a row"
+            ]
+        );
+
+        assert_eq!(
+            answers(11, include_str!("../../day_11/example.txt"), "monkey 0"),
+            ["items: 79, 98\nworry: new = old * 19\ndivisible by 23: throw to monkey 2\notherwise: throw to monkey 3"]
+        );
+    }
+
+    #[test]
+    fn test_session() {
+        let example = include_str!("../../day_8/example.txt");
+
+        assert_eq!(
+            answers(8, example, "\npart 1\npart 2\nfly\nquit\ntree 0 0"),
+            ["21", "8", "error: unknown query `fly`, try `help`"]
+        );
+        assert!(answers(8, example, "help")[0].starts_with("tree X Y"));
+
+        assert!(matches!(
+            run(1, "", "".as_bytes(), Vec::new()),
+            Err(ReplError::Unsupported(1))
+        ));
+        assert!(matches!(
+            run(8, "12\n3", "".as_bytes(), Vec::new()),
+            Err(ReplError::Parse(_))
+        ));
+    }
+}
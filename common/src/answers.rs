@@ -0,0 +1,75 @@
+use crate::{Part, Solution};
+
+/// The confirmed answers of an input, read from an `answers.toml` or an
+/// `example_answers.toml`.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    part_1: Option<String>,
+    part_2: Option<String>,
+}
+
+impl Answers {
+    /// Answers can be written as either numbers or strings.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let table = text.parse::<toml::Table>().map_err(|e| e.to_string())?;
+
+        let answer = |key: &str| match table.get(key) {
+            Some(toml::Value::String(answer)) => Ok(Some(answer.clone())),
+            Some(toml::Value::Integer(answer)) => Ok(Some(answer.to_string())),
+            Some(_) => Err(format!("`{key}` needs to be a number or a string")),
+            None => Ok(None),
+        };
+
+        Ok(Self {
+            part_1: answer("part_1")?,
+            part_2: answer("part_2")?,
+        })
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_1.as_deref(),
+            Part::Two => self.part_2.as_deref(),
+        }
+    }
+}
+
+/// Solves the input, panicking unless each part matches its answer in the
+/// `answers` TOML. Parts without an answer aren't checked. This is what the
+/// days test their examples and bundled inputs with.
+pub fn assert_answers<S: Solution>(input: &str, answers: &str) {
+    let answers = Answers::parse(answers).unwrap_or_else(|e| panic!("Invalid answers: {e}"));
+    let parsed = S::parse(input).unwrap_or_else(|e| panic!("Could not parse the input: {e}"));
+
+    for part in Part::BOTH {
+        if let Some(answer) = answers.get(part) {
+            assert_eq!(
+                part.solve::<S>(&parsed),
+                answer,
+                "Day {} part {part}",
+                S::DAY
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parsing_answers() {
+        let answers = Answers::parse("part_1 = 24000\n").unwrap();
+
+        assert_eq!(answers.get(Part::One), Some("24000"));
+        assert_eq!(answers.get(Part::Two), None);
+
+        let answers = Answers::parse("part_1 = \"CMZ\"\npart_2 = '''\n#.\n.#'''\n").unwrap();
+
+        assert_eq!(answers.get(Part::One), Some("CMZ"));
+        assert_eq!(answers.get(Part::Two), Some("#.\n.#"));
+
+        assert!(Answers::parse("part_1 = [1]").is_err());
+        assert!(Answers::parse("part_1 = ").is_err());
+    }
+}
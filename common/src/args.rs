@@ -0,0 +1,102 @@
+use crate::{Input, Part};
+
+/// The command line arguments each day's binary accepts:
+/// `[--part 1|2] [INPUT]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Args {
+    pub input: Input,
+    /// Which parts to solve. Both, unless `--part` was given.
+    pub parts: Vec<Part>,
+}
+
+impl Args {
+    /// Reads the arguments the binary was started with, or exits with a
+    /// readable message if they don't make sense.
+    pub fn from_env() -> Self {
+        match Self::parse(std::env::args().skip(1)) {
+            Ok(args) => args,
+            Err(e) => {
+                eprintln!("{e}");
+                eprintln!("Usage: [--part 1|2] [INPUT]");
+                std::process::exit(1);
+            }
+        }
+    }
+
+    /// See [`Input::from_str`](std::str::FromStr) for how the input argument is read.
+    /// Anything else starting with `-` is taken to be a mistyped flag rather than a path.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut input = None;
+        let mut part = None;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" => {
+                    let value = args.next().ok_or("`--part` needs a value")?;
+                    part = Some(value.parse::<Part>()?);
+                }
+                _ if arg.starts_with('-') && arg != "-" => {
+                    return Err(format!("unknown flag `{arg}`"));
+                }
+                _ => {
+                    if input.is_some() {
+                        return Err(format!("unexpected argument `{arg}`"));
+                    }
+
+                    input = Some(arg.parse::<Input>().unwrap());
+                }
+            }
+        }
+
+        Ok(Self {
+            input: input.unwrap_or_default(),
+            parts: match part {
+                Some(part) => vec![part],
+                None => Part::BOTH.to_vec(),
+            },
+        })
+    }
+
+    /// Whether the part should be solved.
+    pub fn includes(&self, part: Part) -> bool {
+        self.parts.contains(&part)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        Args::parse(args.iter().map(|x| x.to_string()))
+    }
+
+    #[test]
+    fn test_parsing_args() {
+        assert_eq!(
+            parse(&[]).unwrap(),
+            Args {
+                input: Input::Bundled,
+                parts: Part::BOTH.to_vec(),
+            }
+        );
+        assert_eq!(
+            parse(&["--part", "2", "-"]).unwrap(),
+            Args {
+                input: Input::Stdin,
+                parts: vec![Part::Two],
+            }
+        );
+        assert_eq!(
+            parse(&["input.txt", "--part", "1"]).unwrap().parts,
+            [Part::One]
+        );
+
+        assert!(parse(&["--part"]).is_err());
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["a.txt", "b.txt"]).is_err());
+        assert_eq!(parse(&["--help"]).unwrap_err(), "unknown flag `--help`");
+        assert!(parse(&["-p", "1"]).is_err());
+    }
+}
@@ -0,0 +1,101 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// Where and why a puzzle input could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line number, starting at 1.
+    pub line: usize,
+    /// The character number within the line, starting at 1.
+    pub column: usize,
+    /// The text that could not be parsed. Empty if something was missing.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            line,
+            column,
+            text: text.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Points the error at `token`, which needs to be a slice of `line_text`
+    /// so the column can be worked out from where it starts.
+    pub fn at(line: usize, line_text: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize).saturating_sub(line_text.as_ptr() as usize);
+
+        let column = match line_text.get(..offset) {
+            Some(before) => before.chars().count() + 1,
+            None => 1,
+        };
+
+        Self::new(line, column, token, message)
+    }
+
+    /// Points the error just past the end of the line, for when
+    /// the line stops before everything we need is there.
+    pub fn end_of_line(line: usize, line_text: &str, message: impl Into<String>) -> Self {
+        Self::new(line, line_text.chars().count() + 1, "", message)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+
+        if !self.text.is_empty() {
+            write!(f, " (found {:?})", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token`, a slice of `line_text`, as a number.
+pub fn parse_number<T: FromStr>(
+    line: usize,
+    line_text: &str,
+    token: &str,
+) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(line, line_text, token, "expected a number"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_error_columns() {
+        let line_text = "move 1 from x to 3";
+        let token = line_text.split_whitespace().nth(3).unwrap();
+
+        let error = parse_number::<u64>(4, line_text, token).unwrap_err();
+
+        assert_eq!(error, ParseError::new(4, 13, "x", "expected a number"));
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 13: expected a number (found \"x\")"
+        );
+
+        let error = ParseError::end_of_line(2, "addx", "expected a value");
+
+        assert_eq!(error.column, 5);
+        assert_eq!(error.to_string(), "line 2, column 5: expected a value");
+    }
+}
@@ -0,0 +1,92 @@
+use std::{
+    borrow::Cow,
+    convert::Infallible,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Cursor, Read},
+    path::PathBuf,
+    str::FromStr,
+};
+
+/// Where a day reads its puzzle input from.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Input {
+    /// The `input.txt` that was bundled with the day.
+    #[default]
+    Bundled,
+    Path(PathBuf),
+    Stdin,
+    Text(String),
+}
+
+impl Input {
+    /// Loads the input. `bundled` is what gets returned for [`Input::Bundled`].
+    pub fn read(&self, bundled: &'static str) -> io::Result<Cow<'static, str>> {
+        match self {
+            Input::Bundled => Ok(Cow::Borrowed(bundled)),
+            Input::Path(path) => Ok(Cow::Owned(fs::read_to_string(path)?)),
+            Input::Stdin => {
+                let mut buffer = String::new();
+                io::stdin().read_to_string(&mut buffer)?;
+                Ok(Cow::Owned(buffer))
+            }
+            Input::Text(text) => Ok(Cow::Owned(text.clone())),
+        }
+    }
+
+    /// Opens the input to be read a bit at a time, for days that don't need
+    /// all of it in memory at once.
+    pub fn reader(&self, bundled: &'static str) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            Input::Bundled => Ok(Box::new(bundled.as_bytes())),
+            Input::Path(path) => Ok(Box::new(BufReader::new(File::open(path)?))),
+            Input::Stdin => Ok(Box::new(io::stdin().lock())),
+            Input::Text(text) => Ok(Box::new(Cursor::new(text.clone()))),
+        }
+    }
+}
+
+/// `-` means stdin, anything else is a path to a file.
+impl FromStr for Input {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "-" => Ok(Input::Stdin),
+            _ => Ok(Input::Path(PathBuf::from(s))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reading_inputs() {
+        assert_eq!(Input::Bundled.read("bundled").unwrap(), "bundled");
+        assert_eq!(
+            Input::Text("given".to_string()).read("bundled").unwrap(),
+            "given"
+        );
+        assert!(Input::Path(PathBuf::from("does/not/exist.txt"))
+            .read("bundled")
+            .is_err());
+
+        let mut text = String::new();
+        Input::Text("given".to_string())
+            .reader("bundled")
+            .unwrap()
+            .read_to_string(&mut text)
+            .unwrap();
+        assert_eq!(text, "given");
+        assert!(Input::Path(PathBuf::from("does/not/exist.txt"))
+            .reader("bundled")
+            .is_err());
+
+        assert_eq!("-".parse::<Input>().unwrap(), Input::Stdin);
+        assert_eq!(
+            "input.txt".parse::<Input>().unwrap(),
+            Input::Path(PathBuf::from("input.txt"))
+        );
+    }
+}
@@ -0,0 +1,13 @@
+// Code shared between every day.
+
+mod answers;
+mod args;
+mod error;
+mod input;
+mod solution;
+
+pub use answers::{assert_answers, Answers};
+pub use args::Args;
+pub use error::{parse_number, ParseError};
+pub use input::Input;
+pub use solution::{parse_or_exit, read_or_exit, reader_or_exit, OptionalAnswer, Part, Solution};
@@ -0,0 +1,112 @@
+use std::{borrow::Cow, fmt::Display, io::BufRead, str::FromStr};
+
+use crate::{Input, ParseError};
+
+/// A day's puzzle, split into parsing the input and solving each part.
+pub trait Solution {
+    /// The day number of the puzzle.
+    const DAY: u8;
+    /// The puzzle input bundled with the day.
+    const INPUT: &'static str;
+    /// The confirmed answers for the bundled input, as TOML with a `part_1`
+    /// and `part_2` key. Either can be left out if it isn't known yet.
+    const ANSWERS: &'static str;
+
+    /// What the input parses into. Both parts share the same parsed input.
+    type Parsed;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+    fn part_one(parsed: &Self::Parsed) -> Self::PartOne;
+    fn part_two(parsed: &Self::Parsed) -> Self::PartTwo;
+}
+
+/// Parses the input, or exits with a readable message if it is malformed.
+/// This is what each day's binary uses, since there is nothing else to do
+/// with a bad input there.
+pub fn parse_or_exit<S: Solution>(input: &str) -> S::Parsed {
+    match S::parse(input) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("Could not parse the puzzle input: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Reads the input, or exits with a readable message if it can't be read,
+/// like when the file is missing.
+pub fn read_or_exit<S: Solution>(input: &Input) -> Cow<'static, str> {
+    match input.read(S::INPUT) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("Could not read the puzzle input: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Like [`read_or_exit`], for days that read the input a bit at a time.
+pub fn reader_or_exit<S: Solution>(input: &Input) -> Box<dyn BufRead> {
+    match input.reader(S::INPUT) {
+        Ok(reader) => reader,
+        Err(e) => {
+            eprintln!("Could not read the puzzle input: {e}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// The answer to a part that only some inputs have, so that an input
+/// missing what one part needs can still be parsed and solved for the other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OptionalAnswer<T>(pub Option<T>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    /// Solves this part of the puzzle, formatting the answer.
+    pub fn solve<S: Solution>(&self, parsed: &S::Parsed) -> String {
+        match self {
+            Part::One => S::part_one(parsed).to_string(),
+            Part::Two => S::part_two(parsed).to_string(),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("`{s}` is not a part, expected 1 or 2")),
+        }
+    }
+}
+
+impl<T: Display> Display for OptionalAnswer<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Some(answer) => answer.fmt(f),
+            None => f.pad("no answer"),
+        }
+    }
+}
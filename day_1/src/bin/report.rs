@@ -0,0 +1,15 @@
+// https://adventofcode.com/2022/day/1
+
+use day_1::{report::Report, Day1, Inventory};
+
+fn main() {
+    let report = Report::from_env();
+    let reader = common::reader_or_exit::<Day1>(&report.input);
+
+    let inventory = Inventory::read(reader, report.separators).unwrap_or_else(|e| {
+        eprintln!("Could not read the puzzle input: {e}");
+        std::process::exit(1);
+    });
+
+    print!("{}", report.render(&inventory));
+}
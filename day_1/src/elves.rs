@@ -0,0 +1,338 @@
+use std::io::BufRead;
+
+use crate::{CalorieLines, ReadError, Separators};
+
+/// An elf and the calories of each food item it carries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    /// Where the elf is in the list, starting at 0.
+    pub index: usize,
+    pub items: Vec<u64>,
+}
+
+/// Reads each elf and its items, in the order they are listed.
+pub struct Elves<R> {
+    lines: CalorieLines<R>,
+    next_index: usize,
+}
+
+/// Every elf, ready to be compared with each other.
+#[derive(Debug, Clone)]
+pub struct Inventory {
+    /// In the order they were listed, so an elf's index is its position.
+    elves: Vec<Elf>,
+    /// The indexes of the elves from the most calories to the least. Elves
+    /// with the same total stay in the order they were listed.
+    by_total: Vec<usize>,
+}
+
+/// A range of totals in a histogram, and how many elves fall into it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bucket {
+    pub start: u64,
+    /// Inclusive.
+    pub end: u64,
+    pub elves: usize,
+}
+
+impl Elf {
+    /// The calories of all the elf's items together. [`Elves`] checks that
+    /// this fits in a `u64`, so only elves made up by hand can overflow it.
+    pub fn total(&self) -> u64 {
+        self.items.iter().sum()
+    }
+}
+
+impl<R: BufRead> Elves<R> {
+    pub fn new(reader: R, separators: Separators) -> Self {
+        Self {
+            lines: CalorieLines::new(reader, separators),
+            next_index: 0,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Elves<R> {
+    type Item = Result<Elf, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut items = Vec::new();
+        let mut total = 0u64;
+
+        loop {
+            match self.lines.next::<u64>() {
+                Some(Err(e)) => return Some(Err(e)),
+                Some(Ok(Some(calories))) => match total.checked_add(calories) {
+                    Some(sum) => {
+                        total = sum;
+                        items.push(calories);
+                    }
+                    None => return Some(Err(self.lines.overflow("the elf's total"))),
+                },
+                Some(Ok(None)) if items.is_empty() => (),
+                // The elf is finished, either by a separator or the end of the input.
+                Some(Ok(None)) | None => {
+                    if items.is_empty() {
+                        return None;
+                    }
+
+                    let index = self.next_index;
+                    self.next_index += 1;
+
+                    return Some(Ok(Elf { index, items }));
+                }
+            }
+        }
+    }
+}
+
+impl Inventory {
+    /// Takes the elves in the order they were listed.
+    pub fn new(elves: Vec<Elf>) -> Self {
+        let mut by_total = (0..elves.len()).collect::<Vec<usize>>();
+        by_total.sort_by_key(|&x| std::cmp::Reverse(elves[x].total()));
+
+        Self { elves, by_total }
+    }
+
+    /// Reads every elf. Unlike [`top_elves`](crate::top_elves), this keeps
+    /// all of them (and their items) in memory.
+    pub fn read(reader: impl BufRead, separators: Separators) -> Result<Self, ReadError> {
+        let elves = Elves::new(reader, separators).collect::<Result<Vec<Elf>, ReadError>>()?;
+        Ok(Self::new(elves))
+    }
+
+    /// Every elf, in the order they were listed.
+    pub fn elves(&self) -> &[Elf] {
+        &self.elves
+    }
+
+    pub fn elf(&self, index: usize) -> Option<&Elf> {
+        self.elves.get(index)
+    }
+
+    /// The `k` elves carrying the most calories, from most to least.
+    pub fn top(&self, k: usize) -> impl Iterator<Item = &Elf> {
+        self.by_total.iter().take(k).map(|&x| &self.elves[x])
+    }
+
+    /// Where the elf places by total calories, starting at 1 for the most.
+    /// Elves with the same total share a place.
+    pub fn rank(&self, index: usize) -> Option<usize> {
+        let total = self.elf(index)?.total();
+        Some(self.elves.iter().filter(|x| x.total() > total).count() + 1)
+    }
+
+    /// The average total.
+    pub fn mean(&self) -> Option<f64> {
+        match self.elves.is_empty() {
+            true => None,
+            false => {
+                let sum = self.elves.iter().map(|x| x.total() as f64).sum::<f64>();
+                Some(sum / self.elves.len() as f64)
+            }
+        }
+    }
+
+    /// The middle total, or the average of the two middle ones.
+    pub fn median(&self) -> Option<f64> {
+        let totals = self.sorted_totals();
+        let middle = totals.len() / 2;
+
+        match totals.len() {
+            0 => None,
+            len if len % 2 == 1 => Some(totals[middle] as f64),
+            _ => Some((totals[middle - 1] as f64 + totals[middle] as f64) / 2.0),
+        }
+    }
+
+    /// The smallest total that at least `percent` of the elves are at or below
+    /// (the nearest rank method). `percent` is clamped to 0 to 100.
+    pub fn percentile(&self, percent: f64) -> Option<u64> {
+        let totals = self.sorted_totals();
+
+        if totals.is_empty() {
+            return None;
+        }
+
+        let rank = (percent.clamp(0.0, 100.0) / 100.0 * totals.len() as f64).ceil() as usize;
+        Some(totals[rank.max(1) - 1])
+    }
+
+    /// Splits the range of totals into `buckets` ranges of the same width,
+    /// counting the elves in each one. The last one stops at `u64::MAX` if it
+    /// would go past it. There are never more buckets than different totals.
+    pub fn histogram(&self, buckets: usize) -> Vec<Bucket> {
+        let totals = self.sorted_totals();
+
+        let (Some(&min), Some(&max)) = (totals.first(), totals.last()) else {
+            return Vec::new();
+        };
+
+        let mut distinct = totals.clone();
+        distinct.dedup();
+        let buckets = buckets.min(distinct.len());
+
+        if buckets == 0 {
+            return Vec::new();
+        }
+
+        // A single bucket spanning every `u64` is one wider than a `u64` holds.
+        let width = (max - min) as u128 / buckets as u128 + 1;
+        let clamp = |x: u128| x.min(u64::MAX as u128) as u64;
+
+        let mut histogram = (0..buckets as u128)
+            .map(|i| Bucket {
+                start: clamp(min as u128 + i * width),
+                end: clamp(min as u128 + (i + 1) * width - 1),
+                elves: 0,
+            })
+            .collect::<Vec<Bucket>>();
+
+        for total in totals {
+            histogram[((total - min) as u128 / width) as usize].elves += 1;
+        }
+
+        histogram
+    }
+
+    fn sorted_totals(&self) -> Vec<u64> {
+        self.by_total
+            .iter()
+            .rev()
+            .map(|&x| self.elves[x].total())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Inventory {
+        Inventory::read(
+            include_str!("../example.txt").as_bytes(),
+            Separators::Lenient,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_reading_elves() {
+        let inventory = example();
+
+        assert_eq!(inventory.elves().len(), 5);
+        assert_eq!(
+            inventory.elf(2),
+            Some(&Elf {
+                index: 2,
+                items: vec![5000, 6000]
+            })
+        );
+        assert_eq!(inventory.elf(5), None);
+
+        // Runs of separators and CRLF line endings don't make empty elves.
+        let inventory =
+            Inventory::read("\r\n1\r\n2\r\n\r\n\r\n3\n".as_bytes(), Separators::Lenient).unwrap();
+        assert_eq!(
+            inventory
+                .elves()
+                .iter()
+                .map(Elf::total)
+                .collect::<Vec<u64>>(),
+            [3, 3]
+        );
+    }
+
+    #[test]
+    fn test_ranking_elves() {
+        let inventory = example();
+
+        assert_eq!(
+            inventory.top(3).map(|x| x.index).collect::<Vec<usize>>(),
+            [3, 2, 4]
+        );
+        assert_eq!(inventory.top(10).count(), 5);
+        assert_eq!(inventory.rank(3), Some(1));
+        assert_eq!(inventory.rank(0), Some(4));
+        assert_eq!(inventory.rank(5), None);
+
+        let tied = Inventory::read("5\n\n3\n\n5\n".as_bytes(), Separators::Lenient).unwrap();
+        assert_eq!(tied.rank(2), Some(1));
+        assert_eq!(tied.rank(1), Some(3));
+        assert_eq!(tied.top(2).map(|x| x.index).collect::<Vec<usize>>(), [0, 2]);
+    }
+
+    #[test]
+    fn test_statistics() {
+        let inventory = example();
+
+        assert_eq!(inventory.mean(), Some(11000.0));
+        assert_eq!(inventory.median(), Some(10000.0));
+        assert_eq!(inventory.percentile(20.0), Some(4000));
+        assert_eq!(inventory.percentile(50.0), Some(10000));
+        assert_eq!(inventory.percentile(90.0), Some(24000));
+        assert_eq!(inventory.percentile(0.0), Some(4000));
+
+        let even = Inventory::read("1\n\n4\n".as_bytes(), Separators::Lenient).unwrap();
+        assert_eq!(even.median(), Some(2.5));
+
+        let empty = Inventory::new(Vec::new());
+        assert_eq!(empty.mean(), None);
+        assert_eq!(empty.median(), None);
+        assert_eq!(empty.percentile(50.0), None);
+        assert_eq!(empty.histogram(4), []);
+    }
+
+    #[test]
+    fn test_histogram() {
+        assert_eq!(
+            example().histogram(2),
+            [
+                Bucket {
+                    start: 4000,
+                    end: 14000,
+                    elves: 4
+                },
+                Bucket {
+                    start: 14001,
+                    end: 24001,
+                    elves: 1
+                },
+            ]
+        );
+
+        let same = Inventory::read("7\n\n7\n".as_bytes(), Separators::Lenient).unwrap();
+        assert_eq!(
+            same.histogram(3),
+            [Bucket {
+                start: 7,
+                end: 7,
+                elves: 2
+            }]
+        );
+        assert_eq!(example().histogram(100_000_000_000).len(), 5);
+
+        let widest = Inventory::read(
+            "0\n\n18446744073709551615\n".as_bytes(),
+            Separators::Lenient,
+        )
+        .unwrap();
+        assert_eq!(
+            widest.histogram(1),
+            [Bucket {
+                start: 0,
+                end: u64::MAX,
+                elves: 2
+            }]
+        );
+        assert_eq!(
+            widest
+                .histogram(2)
+                .iter()
+                .map(|x| x.elves)
+                .collect::<Vec<usize>>(),
+            [1, 1]
+        );
+    }
+}
@@ -0,0 +1,413 @@
+// https://adventofcode.com/2022/day/1
+
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fmt::Display,
+    io::{self, BufRead},
+    marker::PhantomData,
+    str::FromStr,
+};
+
+use common::{ParseError, Solution};
+
+mod elves;
+pub mod report;
+
+pub use elves::{Bucket, Elf, Elves, Inventory};
+
+/// The calorie counting puzzle.
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const INPUT: &'static str = include_str!("../input.txt");
+    const ANSWERS: &'static str = include_str!("../answers.toml");
+
+    /// The totals of the top 3 elves, from most to least.
+    type Parsed = Vec<u64>;
+    type PartOne = u64;
+    /// Three totals that each fit in a `u64` always fit together in a `u128`.
+    type PartTwo = u128;
+
+    /// Any line that isn't a number separates two elves, so this only fails
+    /// when there are too many calories to count.
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        top_elves(input.as_bytes(), 3, Separators::Lenient).map_err(|e| match e {
+            ReadError::Parse(e) | ReadError::Overflow(e) => e,
+            ReadError::Io(e) => unreachable!("Reading from a string can't fail: {e}"),
+        })
+    }
+
+    fn part_one(top_elves: &Self::Parsed) -> Self::PartOne {
+        top_elves.first().copied().unwrap_or(0)
+    }
+
+    fn part_two(top_elves: &Self::Parsed) -> Self::PartTwo {
+        top_elves.iter().take(3).map(|&x| x as u128).sum::<u128>()
+    }
+}
+
+/// A number of calories. Adding them up is checked, so a total that doesn't
+/// fit is reported instead of wrapping around.
+pub trait Calories: Clone + Ord + Display + FromStr {
+    fn zero() -> Self;
+
+    /// Returns `None` if the sum doesn't fit.
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+}
+
+impl Calories for u64 {
+    fn zero() -> Self {
+        0
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        u64::checked_add(*self, *other)
+    }
+}
+
+/// Never overflows, for made up inputs with numbers too big for a `u64`.
+#[cfg(feature = "bigint")]
+impl Calories for num_bigint::BigUint {
+    fn zero() -> Self {
+        Self::default()
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+}
+
+/// Which lines separate two elves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Separators {
+    /// Any line that isn't a number, after trimming whitespace from both ends.
+    #[default]
+    Lenient,
+    /// Only empty lines. Any other line has to be a number, so a typo is
+    /// reported instead of splitting an elf in two.
+    Strict,
+}
+
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    /// A line that isn't a number or a separator, in strict mode.
+    Parse(ParseError),
+    /// A line, or an elf's total, has more calories than can be counted.
+    Overflow(ParseError),
+}
+
+/// Reads the total calories held by each elf, in the order they are listed.
+/// Only one line is held in memory at a time, so the input can be as big as it likes.
+pub struct ElfTotals<R, T = u64> {
+    lines: CalorieLines<R>,
+    calories: PhantomData<T>,
+}
+
+/// The biggest totals pushed so far. Only `n` of them are ever kept, in a
+/// min-heap so the smallest one can be swapped out quickly.
+#[derive(Debug, Clone)]
+pub struct TopElves<T = u64> {
+    n: usize,
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+/// Reads the input a line at a time, keeping count of the line number.
+struct CalorieLines<R> {
+    reader: R,
+    separators: Separators,
+    line: String,
+    /// The number of the line that was read last, starting at 1.
+    number: usize,
+}
+
+/// Returns the totals of the `n` elves holding the most calories, from most to least.
+pub fn top_elves<T: Calories>(
+    reader: impl BufRead,
+    n: usize,
+    separators: Separators,
+) -> Result<Vec<T>, ReadError> {
+    let mut top_elves = TopElves::new(n);
+
+    for total in ElfTotals::new(reader, separators) {
+        top_elves.push(total?);
+    }
+
+    Ok(top_elves.totals())
+}
+
+/// Returns the total calories held by each elf, from most to least.
+pub fn elf_cal_counts(input: &str) -> Result<Vec<u64>, ReadError> {
+    let mut elf_cal_counts =
+        ElfTotals::new(input.as_bytes(), Separators::Lenient).collect::<Result<Vec<u64>, _>>()?;
+
+    elf_cal_counts.sort();
+    elf_cal_counts.reverse();
+
+    Ok(elf_cal_counts)
+}
+
+/// Adds up the calories, or returns `None` if they don't fit.
+pub fn checked_sum<T: Calories>(calories: &[T]) -> Option<T> {
+    calories
+        .iter()
+        .try_fold(T::zero(), |sum, x| sum.checked_add(x))
+}
+
+impl<R: BufRead, T: Calories> ElfTotals<R, T> {
+    pub fn new(reader: R, separators: Separators) -> Self {
+        Self {
+            lines: CalorieLines::new(reader, separators),
+            calories: PhantomData,
+        }
+    }
+}
+
+impl<R: BufRead, T: Calories> Iterator for ElfTotals<R, T> {
+    type Item = Result<T, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut total: Option<T> = None;
+
+        loop {
+            match self.lines.next::<T>() {
+                None => return total.map(Ok),
+                Some(Err(e)) => return Some(Err(e)),
+                Some(Ok(Some(calories))) => {
+                    let sum = match total {
+                        Some(total) => total.checked_add(&calories),
+                        None => Some(calories),
+                    };
+
+                    match sum {
+                        Some(sum) => total = Some(sum),
+                        None => return Some(Err(self.lines.overflow("the elf's total"))),
+                    }
+                }
+                // A run of lines that aren't numbers only separates two elves once.
+                Some(Ok(None)) => {
+                    if let Some(total) = total {
+                        return Some(Ok(total));
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<R: BufRead> CalorieLines<R> {
+    fn new(reader: R, separators: Separators) -> Self {
+        Self {
+            reader,
+            separators,
+            line: String::new(),
+            number: 0,
+        }
+    }
+
+    /// Reads the next line, giving `Some` calories for a number and `None`
+    /// for a separator. Returns `None` once there are no lines left.
+    fn next<T: Calories>(&mut self) -> Option<Result<Option<T>, ReadError>> {
+        self.line.clear();
+
+        match self.reader.read_line(&mut self.line) {
+            Ok(0) => return None,
+            Ok(_) => self.number += 1,
+            Err(e) => return Some(Err(ReadError::Io(e))),
+        }
+
+        let text = match self.separators {
+            Separators::Lenient => self.text().trim(),
+            Separators::Strict => self.text(),
+        };
+
+        match text.parse::<T>() {
+            Ok(calories) => Some(Ok(Some(calories))),
+            // Only digits, so it is a number, just too big for `T`.
+            Err(_) if !text.is_empty() && text.bytes().all(|x| x.is_ascii_digit()) => {
+                Some(Err(self.overflow("the number")))
+            }
+            Err(_) if text.is_empty() || self.separators == Separators::Lenient => Some(Ok(None)),
+            Err(_) => Some(Err(ReadError::Parse(ParseError::new(
+                self.number,
+                1,
+                text,
+                "expected a number of calories, or an empty line between two elves",
+            )))),
+        }
+    }
+
+    /// The line that was read last, without its line ending (the same ones
+    /// as `str::lines`).
+    fn text(&self) -> &str {
+        let text = self.line.strip_suffix('\n').unwrap_or(&self.line);
+        text.strip_suffix('\r').unwrap_or(text)
+    }
+
+    /// Points an overflow at the line that was read last, where `what` got too big.
+    fn overflow(&self, what: &str) -> ReadError {
+        ReadError::Overflow(ParseError::new(
+            self.number,
+            1,
+            self.text(),
+            format!("{what} has too many calories to count"),
+        ))
+    }
+}
+
+impl<T: Calories> TopElves<T> {
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    /// Keeps the total if it is one of the `n` biggest so far.
+    pub fn push(&mut self, total: T) {
+        if self.heap.len() < self.n {
+            self.heap.push(Reverse(total));
+            return;
+        }
+
+        if let Some(mut smallest) = self.heap.peek_mut() {
+            if total > smallest.0 {
+                *smallest = Reverse(total);
+            }
+        }
+    }
+
+    /// The totals that were kept, from most to least.
+    pub fn totals(&self) -> Vec<T> {
+        let mut totals = self.heap.iter().map(|x| x.0.clone()).collect::<Vec<T>>();
+        totals.sort_by(|a, b| b.cmp(a));
+        totals
+    }
+}
+
+impl std::fmt::Display for ReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "{e}"),
+            ReadError::Parse(e) | ReadError::Overflow(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        common::assert_answers::<Day1>(EXAMPLE, include_str!("../example_answers.toml"));
+    }
+
+    #[test]
+    fn test_input() {
+        common::assert_answers::<Day1>(Day1::INPUT, Day1::ANSWERS);
+    }
+
+    #[test]
+    fn test_top_elves() {
+        let all = elf_cal_counts(Day1::INPUT).unwrap();
+
+        for n in [0, 1, 3, 10, all.len(), all.len() + 5, usize::MAX] {
+            let top = top_elves::<u64>(Day1::INPUT.as_bytes(), n, Separators::Lenient).unwrap();
+            assert_eq!(top, all[..n.min(all.len())]);
+        }
+
+        assert_eq!(
+            elf_cal_counts("1\r\n2\r\n\r\n\r\n4\nx\n5\n\n\n").unwrap(),
+            [5, 4, 3]
+        );
+        assert_eq!(
+            top_elves::<u64>("".as_bytes(), 3, Separators::Strict).unwrap(),
+            []
+        );
+    }
+
+    #[test]
+    fn test_separators() {
+        let totals = |input: &str, separators| {
+            ElfTotals::new(input.as_bytes(), separators).collect::<Result<Vec<u64>, ReadError>>()
+        };
+
+        // Whitespace around a number, or on its own, is fine when lenient.
+        assert_eq!(
+            totals("1 \r\n 2\t\r\n  \r\n3\r\n\t\n4", Separators::Lenient).unwrap(),
+            [3, 3, 4]
+        );
+        assert_eq!(totals("1\n12a4\n2", Separators::Lenient).unwrap(), [1, 2]);
+
+        assert_eq!(
+            totals("1\r\n2\r\n\r\n\r\n3\r\n", Separators::Strict).unwrap(),
+            [3, 3]
+        );
+
+        let Err(ReadError::Parse(error)) = totals("1\n\n12a4\n2", Separators::Strict) else {
+            panic!("expected `12a4` to be reported");
+        };
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: expected a number of calories, or an empty line \
+             between two elves (found \"12a4\")"
+        );
+        assert!(matches!(
+            totals("1\n \n2", Separators::Strict),
+            Err(ReadError::Parse(ParseError { line: 2, .. }))
+        ));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max = u64::MAX;
+
+        // The biggest totals that fit are still counted.
+        let parsed = Day1::parse(&format!("{max}\n\n{max}\n\n{max}\n")).unwrap();
+        assert_eq!(Day1::part_two(&parsed), 3 * max as u128);
+
+        let error = Day1::parse(&format!("1\n\n{max}\n1\n")).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(4, 1, "1", "the elf's total has too many calories to count")
+        );
+
+        let error = Day1::parse("1\n\n18446744073709551616\r\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: the number has too many calories to count \
+             (found \"18446744073709551616\")"
+        );
+
+        assert!(matches!(
+            Inventory::read(format!("{max}\n{max}").as_bytes(), Separators::Lenient),
+            Err(ReadError::Overflow(_))
+        ));
+        assert_eq!(checked_sum(&[max, 1]), None);
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_big_totals() {
+        use num_bigint::BigUint;
+
+        let input = "18446744073709551615\n18446744073709551615\n\n1\n\n100000000000000000000000";
+        let top = top_elves::<BigUint>(input.as_bytes(), 2, Separators::Strict).unwrap();
+
+        assert_eq!(
+            top.iter().map(|x| x.to_string()).collect::<Vec<String>>(),
+            ["100000000000000000000000", "36893488147419103230"]
+        );
+        assert_eq!(
+            checked_sum(&top).unwrap().to_string(),
+            "100036893488147419103230"
+        );
+    }
+}
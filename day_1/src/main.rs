@@ -1,40 +1,82 @@
 // https://adventofcode.com/2022/day/1
 
+use common::{Args, Part};
+use day_1::{Calories, Day1, Separators};
+
+#[cfg(feature = "bigint")]
+type Total = num_bigint::BigUint;
+#[cfg(not(feature = "bigint"))]
+type Total = u64;
+
+/// The flags only day 1 has.
+struct Options {
+    separators: Separators,
+    /// How many of the elves carrying the most to add up for part 2.
+    top: usize,
+}
+
 fn main() {
-    let parsed_lines = include_str!("../input.txt")
-        .lines()
-        .map(|chunk| match chunk.parse::<u64>() {
-            Ok(x) => Some(x),
-            Err(_) => None,
-        })
-        .collect::<Vec<Option<u64>>>();
-
-    let mut elf_cal_counts = Vec::new();
-    let mut needs_regrouping = true;
-    for line in parsed_lines {
-        if line.is_none() {
-            needs_regrouping = true;
-            continue;
-        }
+    let (options, args) = split_options(std::env::args().skip(1)).unwrap_or_else(|e| exit(&e));
+    let args = Args::parse(args).unwrap_or_else(|e| exit(&e));
 
-        if needs_regrouping {
-            elf_cal_counts.push(0);
-            needs_regrouping = false;
-        }
+    let reader = common::reader_or_exit::<Day1>(&args.input);
 
-        let i = elf_cal_counts.len() - 1;
-        elf_cal_counts[i] += line.unwrap();
+    // Only the top elves are kept while reading, so big inputs don't need to fit in memory.
+    let top_elves = day_1::top_elves::<Total>(reader, options.top, options.separators)
+        .unwrap_or_else(|e| {
+            eprintln!("Could not read the puzzle input: {e}");
+            std::process::exit(1);
+        });
+
+    if args.includes(Part::One) {
+        let most = top_elves.first().cloned().unwrap_or_else(Total::zero);
+        println!("Most Calories Held By Elf: {most}");
     }
 
-    elf_cal_counts.sort();
-    elf_cal_counts.reverse();
+    if args.includes(Part::Two) {
+        match day_1::checked_sum(&top_elves) {
+            Some(sum) => println!("Most Calories Held By Top {} Elves: {sum}", options.top),
+            None => {
+                eprintln!(
+                    "The top {} elves have too many calories to count together, \
+                     try building with `--features bigint`",
+                    options.top
+                );
+                std::process::exit(1);
+            }
+        }
+    }
+}
 
-    println!(
-        "Most Calories Held By Elf: {}",
-        elf_cal_counts.iter().take(1).sum::<u64>()
-    );
+/// Takes the flags only day 1 has out of the arguments, leaving the ones
+/// every day accepts.
+fn split_options(mut args: impl Iterator<Item = String>) -> Result<(Options, Vec<String>), String> {
+    let mut options = Options {
+        separators: Separators::Lenient,
+        top: 3,
+    };
+    let mut rest = Vec::new();
 
-    let top_three_total = elf_cal_counts.iter().take(3).sum::<u64>();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--strict" => options.separators = Separators::Strict,
+            "--top" => {
+                let value = args.next().ok_or("`--top` needs a number of elves")?;
+
+                options.top = match value.parse::<usize>() {
+                    Ok(top) if top > 0 => top,
+                    _ => return Err(format!("`{value}` is not a number of elves above 0")),
+                };
+            }
+            _ => rest.push(arg),
+        }
+    }
+
+    Ok((options, rest))
+}
 
-    println!("Most Calories Held By Top 3 Elves: {}", top_three_total);
+fn exit(message: &str) -> ! {
+    eprintln!("{message}");
+    eprintln!("Usage: [--strict] [--top N] [--part 1|2] [INPUT]");
+    std::process::exit(1);
 }
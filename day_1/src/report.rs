@@ -0,0 +1,265 @@
+use std::{fmt::Write, str::FromStr};
+
+use common::Input;
+
+use crate::{Inventory, Separators};
+
+/// What the `report` binary prints about the elves:
+/// `[--strict] [--top K] [--rank INDEX]... [--percentile P]... [--buckets N] [INPUT]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub input: Input,
+    pub separators: Separators,
+    /// How many of the elves carrying the most to list.
+    pub top: usize,
+    /// The indexes of the elves to show the place of.
+    pub ranks: Vec<usize>,
+    pub percentiles: Vec<f64>,
+    /// How many bars the histogram has.
+    pub buckets: usize,
+}
+
+const USAGE: &str =
+    "Usage: [--strict] [--top K] [--rank INDEX]... [--percentile P]... [--buckets N] [INPUT]";
+
+/// The widest a bar of the histogram gets.
+const BAR_WIDTH: usize = 40;
+
+impl Default for Report {
+    fn default() -> Self {
+        Self {
+            input: Input::Bundled,
+            separators: Separators::Lenient,
+            top: 3,
+            ranks: Vec::new(),
+            percentiles: vec![50.0, 90.0, 99.0],
+            buckets: 10,
+        }
+    }
+}
+
+impl Report {
+    /// Reads the arguments the binary was started with, or exits with a
+    /// readable message if they don't make sense.
+    pub fn from_env() -> Self {
+        match Self::parse(std::env::args().skip(1)) {
+            Ok(report) => report,
+            Err(e) => {
+                eprintln!("{e}");
+                eprintln!("{USAGE}");
+                std::process::exit(1);
+            }
+        }
+    }
+
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut report = Self::default();
+        let mut input = None;
+        let mut percentiles = Vec::new();
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--strict" => report.separators = Separators::Strict,
+                "--top" => report.top = value(&mut args, &arg, "a number of elves")?,
+                "--rank" => report.ranks.push(value(&mut args, &arg, "an elf's index")?),
+                "--percentile" => {
+                    let percent: f64 = value(&mut args, &arg, "a percentage")?;
+
+                    if !(0.0..=100.0).contains(&percent) {
+                        return Err(format!("`{percent}` is not between 0 and 100"));
+                    }
+
+                    percentiles.push(percent);
+                }
+                "--buckets" => report.buckets = value(&mut args, &arg, "a number of buckets")?,
+                _ if arg.starts_with('-') && arg != "-" => {
+                    return Err(format!("unknown flag `{arg}`"));
+                }
+                _ => {
+                    if input.is_some() {
+                        return Err(format!("unexpected argument `{arg}`"));
+                    }
+
+                    input = Some(arg.parse::<Input>().unwrap());
+                }
+            }
+        }
+
+        report.input = input.unwrap_or_default();
+
+        if !percentiles.is_empty() {
+            report.percentiles = percentiles;
+        }
+
+        Ok(report)
+    }
+
+    /// Describes the elves in the inventory.
+    pub fn render(&self, inventory: &Inventory) -> String {
+        let elves = inventory.elves();
+
+        // Writing to a string can't fail, so the results are ignored.
+        let mut text = String::new();
+        let items = elves.iter().map(|x| x.items.len()).sum::<usize>();
+        let _ = writeln!(text, "{} elves carrying {items} items", elves.len());
+
+        let (Some(mean), Some(median)) = (inventory.mean(), inventory.median()) else {
+            return text;
+        };
+
+        let _ = writeln!(text, "Mean: {mean:.1} calories");
+        let _ = writeln!(text, "Median: {median:.1} calories");
+
+        let _ = writeln!(text, "\nTop {}:", self.top.min(elves.len()));
+        for (place, elf) in inventory.top(self.top).enumerate() {
+            let items = match elf.items.len() {
+                1 => "item",
+                _ => "items",
+            };
+
+            let _ = writeln!(
+                text,
+                "  {:>3}. Elf {}: {} calories in {} {items}",
+                place + 1,
+                elf.index,
+                elf.total(),
+                elf.items.len()
+            );
+        }
+
+        if !self.ranks.is_empty() {
+            let _ = writeln!(text, "\nRanks:");
+        }
+
+        for &index in &self.ranks {
+            let _ = match (inventory.elf(index), inventory.rank(index)) {
+                (Some(elf), Some(rank)) => writeln!(
+                    text,
+                    "  Elf {index}: {rank} of {}, with {} calories",
+                    elves.len(),
+                    elf.total()
+                ),
+                _ => writeln!(text, "  Elf {index}: not in the list"),
+            };
+        }
+
+        let _ = writeln!(text, "\nPercentiles:");
+        for &percent in &self.percentiles {
+            if let Some(total) = inventory.percentile(percent) {
+                let _ = writeln!(text, "  p{percent}: {total} calories");
+            }
+        }
+
+        let histogram = inventory.histogram(self.buckets);
+        let most = histogram.iter().map(|x| x.elves).max().unwrap_or(0).max(1);
+        let digits = histogram.last().map_or(1, |x| x.end.to_string().len());
+
+        let _ = writeln!(text, "\nHistogram:");
+        for bucket in histogram {
+            let bar = "#".repeat(bucket.elves * BAR_WIDTH / most);
+            let _ = writeln!(
+                text,
+                "  {:>digits$} - {:>digits$}  {bar:<BAR_WIDTH$} {}",
+                bucket.start, bucket.end, bucket.elves
+            );
+        }
+
+        text
+    }
+}
+
+/// Parses the value following the flag.
+fn value<T: FromStr>(
+    args: &mut impl Iterator<Item = String>,
+    flag: &str,
+    what: &str,
+) -> Result<T, String> {
+    let value = args.next().ok_or(format!("`{flag}` needs {what}"))?;
+
+    value
+        .parse()
+        .map_err(|_| format!("`{value}` is not {what}"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Report, String> {
+        Report::parse(args.iter().map(|x| x.to_string()))
+    }
+
+    #[test]
+    fn test_parsing_args() {
+        assert_eq!(parse(&[]).unwrap(), Report::default());
+
+        let report = parse(&[
+            "--top",
+            "5",
+            "--rank",
+            "2",
+            "--rank",
+            "0",
+            "--percentile",
+            "75",
+            "--strict",
+            "-",
+        ])
+        .unwrap();
+        assert_eq!(report.separators, Separators::Strict);
+        assert_eq!(report.top, 5);
+        assert_eq!(report.ranks, [2, 0]);
+        assert_eq!(report.percentiles, [75.0]);
+        assert_eq!(report.input, Input::Stdin);
+
+        assert!(parse(&["--top"]).is_err());
+        assert!(parse(&["--buckets", "many"]).is_err());
+        assert!(parse(&["--percentile", "101"]).is_err());
+        assert!(parse(&["a.txt", "b.txt"]).is_err());
+        assert_eq!(
+            parse(&["--bucket", "2"]).unwrap_err(),
+            "unknown flag `--bucket`"
+        );
+    }
+
+    #[test]
+    fn test_rendering() {
+        let inventory = Inventory::read(
+            include_str!("../example.txt").as_bytes(),
+            Separators::Strict,
+        )
+        .unwrap();
+        let report =
+            parse(&["--top", "2", "--rank", "0", "--rank", "9", "--buckets", "2"]).unwrap();
+
+        let expected = "\
+5 elves carrying 10 items
+Mean: 11000.0 calories
+Median: 10000.0 calories
+
+Top 2:
+    1. Elf 3: 24000 calories in 3 items
+    2. Elf 2: 11000 calories in 2 items
+
+Ranks:
+  Elf 0: 4 of 5, with 6000 calories
+  Elf 9: not in the list
+
+Percentiles:
+  p50: 10000 calories
+  p90: 24000 calories
+  p99: 24000 calories
+
+Histogram:
+   4000 - 14000  ######################################## 4
+  14001 - 24001  ##########                               1
+";
+
+        assert_eq!(report.render(&inventory), expected);
+        assert_eq!(
+            report.render(&Inventory::new(Vec::new())),
+            "0 elves carrying 0 items\n"
+        );
+    }
+}
@@ -0,0 +1,316 @@
+// https://adventofcode.com/2022/day/10
+
+use common::{parse_number, OptionalAnswer, ParseError, Solution};
+use grid::Grid;
+use tracing::{debug, debug_span, trace};
+
+/// The cycles to measure the signal strength at for part 1.
+pub const SIGNAL_CYCLES: [u64; 6] = [20, 60, 100, 140, 180, 220];
+
+/// The amount of pixels in a row of the CRT.
+pub const CRT_WIDTH: usize = 40;
+
+/// The cathode-ray tube puzzle.
+pub struct Day10;
+
+/// A single CPU instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Addx(i64),
+    Noop,
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const INPUT: &'static str = include_str!("../input.txt");
+    const ANSWERS: &'static str = include_str!("../answers.toml");
+
+    type Parsed = Vec<Instruction>;
+    /// The register times the cycle can be too big for an `i64`. Programs
+    /// that stop before the last signal cycle have no answer.
+    type PartOne = OptionalAnswer<i128>;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let instructions = generate_instructions(input)?;
+
+        // Each instruction is on its own line, since empty lines aren't allowed.
+        let mut register = 1i64;
+        for (i, (instruction, line)) in instructions.iter().zip(input.lines()).enumerate() {
+            if let Instruction::Addx(value) = instruction {
+                register = register.checked_add(*value).ok_or_else(|| {
+                    let token = line.split_whitespace().nth(1).unwrap_or(line);
+                    ParseError::at(i + 1, line, token, "the register overflows adding this")
+                })?;
+            }
+        }
+
+        Ok(instructions)
+    }
+
+    fn part_one(instructions: &Self::Parsed) -> Self::PartOne {
+        let signal_strengths = find_signal_strengths(instructions, &SIGNAL_CYCLES);
+        OptionalAnswer(signal_strengths.map(|x| x.iter().sum::<i128>()))
+    }
+
+    fn part_two(instructions: &Self::Parsed) -> Self::PartTwo {
+        render(instructions)
+    }
+}
+
+/// The value of the register during each cycle, starting with the first.
+/// Panics if the register overflows, which [`Day10::parse`] rejects.
+pub fn register_values(instructions: &[Instruction]) -> Vec<i64> {
+    let mut register = 1i64;
+    let mut values = Vec::new();
+
+    for instruction in instructions {
+        // The register only changes once the instruction has finished.
+        match instruction {
+            Instruction::Addx(inc) => {
+                values.push(register);
+                trace!(cycle = values.len(), register, ?instruction, "cycle");
+                values.push(register);
+                trace!(cycle = values.len(), register, ?instruction, "cycle");
+                register += inc;
+            }
+            Instruction::Noop => {
+                values.push(register);
+                trace!(cycle = values.len(), register, ?instruction, "cycle");
+            }
+        }
+    }
+
+    values
+}
+
+/// The signal strength (the cycle times the register) during each of the given cycles,
+/// or `None` if the program stops before one of them.
+pub fn find_signal_strengths(
+    instructions: &[Instruction],
+    at_clock_cycles: &[u64],
+) -> Option<Vec<i128>> {
+    let _span = debug_span!("find_signal_strengths").entered();
+    let register_values = register_values(instructions);
+
+    at_clock_cycles
+        .iter()
+        .map(|&cycle| {
+            let register = *register_values.get((cycle as usize).checked_sub(1)?)?;
+            let strength = register as i128 * cycle as i128;
+            debug!(cycle, strength, "signal strength");
+            Some(strength)
+        })
+        .collect()
+}
+
+/// Runs the instructions and returns the lines drawn on the CRT.
+pub fn render(instructions: &[Instruction]) -> String {
+    match screen(instructions) {
+        Some(screen) => screen.render(|&lit| match lit {
+            true => '#',
+            false => '.',
+        }),
+        None => String::new(),
+    }
+}
+
+/// Which pixels of the CRT are lit once the instructions have run.
+/// Returns `None` if the program doesn't run long enough to draw a single row.
+pub fn screen(instructions: &[Instruction]) -> Option<Grid<bool>> {
+    let pixels = register_values(instructions)
+        .iter()
+        .enumerate()
+        .map(|(i, &x_pos)| pixel(i, x_pos))
+        .collect::<Vec<bool>>();
+
+    // A row that isn't finished by the end of the program doesn't get drawn.
+    let rows = pixels
+        .chunks_exact(CRT_WIDTH)
+        .map(|row| row.to_vec())
+        .collect::<Vec<Vec<bool>>>();
+
+    Grid::from_rows(rows)
+}
+
+/// Whether the pixel (counting from 0) is on, meaning it is covered by the
+/// 3 pixel wide sprite centered on `x_pos`.
+pub fn pixel(pixel_index: usize, x_pos: i64) -> bool {
+    let pixel = (pixel_index % CRT_WIDTH) as i64;
+    ((pixel - 1)..=(pixel + 1)).contains(&x_pos)
+}
+
+/// Parses one instruction per line.
+pub fn generate_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, x)| {
+            let line_number = i + 1;
+            let split = x.split_whitespace().collect::<Vec<&str>>();
+
+            match split.first() {
+                Some(&"addx") => match split.get(1) {
+                    Some(value) => Ok(Instruction::Addx(parse_number(line_number, x, value)?)),
+                    None => Err(ParseError::end_of_line(
+                        line_number,
+                        x,
+                        "expected a value to add",
+                    )),
+                },
+                Some(&"noop") => Ok(Instruction::Noop),
+                Some(name) => Err(ParseError::at(
+                    line_number,
+                    x,
+                    name,
+                    "expected `addx` or `noop`",
+                )),
+                None => Err(ParseError::new(
+                    line_number,
+                    1,
+                    "",
+                    "expected `addx` or `noop`",
+                )),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use grid::Point;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        common::assert_answers::<Day10>(EXAMPLE, include_str!("../example_answers.toml"));
+    }
+
+    #[test]
+    fn test_input() {
+        common::assert_answers::<Day10>(Day10::INPUT, Day10::ANSWERS);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let noops = "noop\n".repeat(220);
+
+        let error =
+            Day10::parse(&format!("addx 9223372036854775807\naddx 1\n{noops}")).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(
+                1,
+                6,
+                "9223372036854775807",
+                "the register overflows adding this"
+            )
+        );
+
+        let error =
+            Day10::parse(&format!("addx 9223372036854775806\naddx 1\n{noops}")).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 6));
+
+        // The biggest register there can be still gets a signal strength.
+        let parsed = Day10::parse(&format!("addx 9223372036854775806\n{noops}")).unwrap();
+        assert_eq!(
+            Day10::part_one(&parsed),
+            OptionalAnswer(Some(i64::MAX as i128 * 720))
+        );
+    }
+
+    #[test]
+    fn test_short_programs() {
+        // Part 2 draws nothing, but part 1 can't measure the last signal cycle.
+        let parsed = Day10::parse("noop\n").unwrap();
+        assert_eq!(Day10::part_one(&parsed), OptionalAnswer(None));
+        assert_eq!(Day10::part_two(&parsed), "");
+
+        let parsed = Day10::parse(&"noop\n".repeat(220)).unwrap();
+        assert_eq!(Day10::part_one(&parsed), OptionalAnswer(Some(720)));
+    }
+
+    fn program() -> impl Strategy<Value = Vec<Instruction>> {
+        let instruction = prop_oneof![
+            Just(Instruction::Noop),
+            (-40..=40i64).prop_map(Instruction::Addx),
+        ];
+
+        prop::collection::vec(instruction, 0..300)
+    }
+
+    fn program_text(program: &[Instruction]) -> String {
+        program
+            .iter()
+            .map(|x| match x {
+                Instruction::Addx(value) => format!("addx {value}\n"),
+                Instruction::Noop => "noop\n".to_string(),
+            })
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn test_register_only_changes_after_addx(program in program()) {
+            let register_values = register_values(&program);
+            let mut cycles = register_values.iter();
+            let mut register = 1;
+
+            for instruction in &program {
+                match instruction {
+                    Instruction::Addx(value) => {
+                        prop_assert_eq!(cycles.next(), Some(&register));
+                        prop_assert_eq!(cycles.next(), Some(&register));
+                        register += value;
+                    }
+                    Instruction::Noop => prop_assert_eq!(cycles.next(), Some(&register)),
+                }
+            }
+
+            prop_assert_eq!(cycles.next(), None);
+        }
+
+        #[test]
+        fn test_signal_strengths(program in program()) {
+            let register_values = register_values(&program);
+            let cycles = (1..=register_values.len() as u64).step_by(7).collect::<Vec<u64>>();
+
+            let strengths = find_signal_strengths(&program, &cycles).unwrap();
+            prop_assert_eq!(find_signal_strengths(&program, &[register_values.len() as u64 + 1]), None);
+
+            for (cycle, strength) in cycles.iter().zip(strengths) {
+                prop_assert_eq!(strength, *cycle as i128 * register_values[*cycle as usize - 1] as i128);
+            }
+        }
+
+        #[test]
+        fn test_screen_follows_sprite(program in program()) {
+            let register_values = register_values(&program);
+
+            let Some(screen) = screen(&program) else {
+                prop_assert!(register_values.len() < CRT_WIDTH);
+                return Ok(());
+            };
+
+            prop_assert_eq!(screen.width(), CRT_WIDTH);
+            prop_assert_eq!(screen.height(), register_values.len() / CRT_WIDTH);
+
+            for (i, &x_pos) in register_values.iter().enumerate().take(screen.width() * screen.height()) {
+                let column = (i % CRT_WIDTH) as i64;
+                let row_from_top = (i / CRT_WIDTH) as i64;
+                let point = Point::new(column, screen.height() as i64 - 1 - row_from_top);
+
+                prop_assert_eq!(screen[point], (x_pos - column).abs() <= 1);
+            }
+        }
+
+        #[test]
+        fn test_parsing_programs(program in program()) {
+            prop_assert_eq!(generate_instructions(&program_text(&program)).unwrap(), program);
+        }
+    }
+}
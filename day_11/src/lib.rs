@@ -0,0 +1,467 @@
+// https://adventofcode.com/2022/day/11
+
+use std::cell::RefCell;
+
+use common::{parse_number, ParseError, Solution};
+use tracing::{debug_span, trace};
+
+/// The monkey in the middle puzzle.
+pub struct Day11;
+
+/// If the inner value is `None`, it means to do the operation with itself (old * old).
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Add(Option<u64>),
+    Multiply(Option<u64>),
+}
+
+/// A monkey and the items it is holding.
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    /// The worry levels of the items the monkey is holding.
+    // We have to use a RefCell here as we need to mutably
+    // change values, but not at the same time.
+    pub items: RefCell<Vec<u64>>,
+    pub operation: Operation,
+    pub divisible_by: u64,
+    /// If the worry value is divisble by self.divisble_by, throw to this monkey.
+    pub on_success: usize,
+    /// Follows the same logic as self.on_succes
+    pub on_fail: usize,
+    pub total_inspections: RefCell<u64>,
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const INPUT: &'static str = include_str!("../input.txt");
+    const ANSWERS: &'static str = include_str!("../answers.toml");
+
+    type Parsed = Vec<Monkey>;
+    type PartOne = u128;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generate_monkeys(input)
+    }
+
+    fn part_one(monkeys: &Self::Parsed) -> Self::PartOne {
+        let mut monkeys = monkeys.clone();
+
+        for _ in 0..20 {
+            run_cycle(&mut monkeys, true);
+        }
+
+        calculate_monkey_business(&monkeys)
+    }
+
+    fn part_two(monkeys: &Self::Parsed) -> Self::PartTwo {
+        let mut monkeys = monkeys.clone();
+
+        for _ in 0..10000 {
+            run_cycle(&mut monkeys, false);
+        }
+
+        calculate_monkey_business(&monkeys)
+    }
+}
+
+/// The inspection counts of the two busiest monkeys multiplied together.
+pub fn calculate_monkey_business(monkeys: &[Monkey]) -> u128 {
+    let mut inspection_totals = monkeys
+        .iter()
+        .map(|x| *x.total_inspections.borrow())
+        .collect::<Vec<u64>>();
+
+    inspection_totals.sort();
+
+    let top = inspection_totals.pop().unwrap();
+    let second_top = inspection_totals.pop().unwrap();
+
+    top as u128 * second_top as u128
+}
+
+/// Every monkey takes a turn inspecting and throwing all of its items.
+/// Panics if the divisors multiplied together don't fit in a `u64`, which
+/// [`generate_monkeys`] rejects.
+pub fn run_cycle(monkeys: &mut [Monkey], drop_worry_levels: bool) {
+    // This is the factor we can modulo by and not have checks affected
+    let shared_factor = monkeys.iter().map(|x| x.divisible_by).product::<u64>();
+    let _span = debug_span!("run_cycle", shared_factor).entered();
+
+    for (i, monkey) in monkeys.iter().enumerate() {
+        // We drain as we're going to be moving these values to another inventory each time
+        for mut item in monkey.items.borrow_mut().drain(..).collect::<Vec<u64>>() {
+            // For each item we increment the total inspections
+            // Yes I have to assign values like this so they don't conflict
+            let new_total_inspections = *monkey.total_inspections.borrow() + 1;
+            *monkey.total_inspections.borrow_mut() = new_total_inspections;
+
+            // We do the monkey operation, which can't overflow a u128 since
+            // both sides fit in a u64
+            let mut worry = match monkey.operation {
+                Operation::Add(possible_n) => match possible_n {
+                    Some(n) => item as u128 + n as u128,
+                    None => item as u128 + item as u128,
+                },
+                Operation::Multiply(possible_n) => match possible_n {
+                    Some(n) => item as u128 * n as u128,
+                    None => item as u128 * item as u128,
+                },
+            };
+
+            // We do the worry divided by 3 (rounded down) if needed
+            if drop_worry_levels {
+                worry /= 3;
+            }
+
+            // Less than the shared factor, so it fits in a u64 again
+            item = (worry % shared_factor as u128) as u64;
+
+            // We do the test and then throw it if needed
+            let target = match (item % monkey.divisible_by) == 0 {
+                true => monkey.on_success,
+                false => monkey.on_fail,
+            };
+
+            trace!(monkey = i, item, to = target, "threw item");
+            monkeys[target].items.borrow_mut().push(item);
+        }
+    }
+}
+
+// Just for fun i wanted to cram all the parsing into one iter chain
+/// Parses the notes on every monkey, which are separated by blank lines.
+pub fn generate_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let lines = input.lines().collect::<Vec<&str>>();
+    let mut shared_factor = 1u64;
+
+    let monkeys = lines
+        .chunks(7)
+        .enumerate()
+        .map(|(i, monkey_data)| {
+            let first_line_number = i * 7 + 1;
+
+            if monkey_data.len() < 6 {
+                let last_index = monkey_data.len() - 1;
+
+                return Err(ParseError::end_of_line(
+                    first_line_number + last_index,
+                    monkey_data[last_index],
+                    "expected 6 lines describing the monkey",
+                ));
+            }
+
+            // Returns the line number, the line, and the text after the given field name.
+            let field = |index: usize, name: &str| {
+                let line_number = first_line_number + index;
+                let line = monkey_data[index];
+
+                match line.trim().strip_prefix(name) {
+                    Some(value) => Ok((line_number, line, value.trim())),
+                    None => Err(ParseError::at(
+                        line_number,
+                        line,
+                        line.trim(),
+                        format!("expected `{name}`"),
+                    )),
+                }
+            };
+
+            let items = {
+                let (line_number, line, raw_items) = field(1, "Starting items:")?;
+
+                let items = match raw_items.is_empty() {
+                    true => Vec::new(),
+                    false => raw_items
+                        .split(',')
+                        .map(|x| parse_number::<u64>(line_number, line, x.trim()))
+                        .collect::<Result<Vec<u64>, ParseError>>()?,
+                };
+
+                RefCell::new(items)
+            };
+
+            let operation = {
+                let (line_number, line, raw_operation) = field(2, "Operation: new = old")?;
+                let operation_data = raw_operation.split_whitespace().collect::<Vec<&str>>();
+
+                if operation_data.len() < 2 {
+                    return Err(ParseError::end_of_line(
+                        line_number,
+                        line,
+                        "expected an operation like `* 19` or `+ old`",
+                    ));
+                }
+
+                let operand = match operation_data[1] {
+                    "old" => None,
+                    x => Some(parse_number(line_number, line, x)?),
+                };
+
+                match operation_data[0] {
+                    "+" => Operation::Add(operand),
+                    "*" => Operation::Multiply(operand),
+                    x => return Err(ParseError::at(line_number, line, x, "expected `+` or `*`")),
+                }
+            };
+
+            let divisible_by = {
+                let (line_number, line, raw_divisor) = field(3, "Test: divisible by")?;
+                let divisible_by = parse_number::<u64>(line_number, line, raw_divisor)?;
+
+                if divisible_by == 0 {
+                    return Err(ParseError::at(
+                        line_number,
+                        line,
+                        raw_divisor,
+                        "can't test if something is divisible by 0",
+                    ));
+                }
+
+                // The worry levels are kept below every divisor multiplied together.
+                shared_factor = shared_factor.checked_mul(divisible_by).ok_or_else(|| {
+                    ParseError::at(
+                        line_number,
+                        line,
+                        raw_divisor,
+                        "the divisors multiplied together don't fit in a u64",
+                    )
+                })?;
+
+                divisible_by
+            };
+
+            let (line_number, line, raw_target) = field(4, "If true: throw to monkey")?;
+            let on_success = parse_number::<usize>(line_number, line, raw_target)?;
+
+            let (line_number, line, raw_target) = field(5, "If false: throw to monkey")?;
+            let on_fail = parse_number::<usize>(line_number, line, raw_target)?;
+
+            let total_inspections = RefCell::new(0);
+
+            Ok(Monkey {
+                items,
+                operation,
+                divisible_by,
+                on_success,
+                on_fail,
+                total_inspections,
+            })
+        })
+        .collect::<Result<Vec<Monkey>, ParseError>>()?;
+
+    if monkeys.len() < 2 {
+        return Err(ParseError::new(
+            lines.len() + 1,
+            1,
+            "",
+            "expected at least 2 monkeys",
+        ));
+    }
+
+    // The monkeys have to throw to monkeys that exist.
+    for (i, monkey) in monkeys.iter().enumerate() {
+        for (offset, target) in [(4, monkey.on_success), (5, monkey.on_fail)] {
+            if target >= monkeys.len() {
+                let line_number = i * 7 + offset + 1;
+                let line = lines[line_number - 1];
+
+                return Err(ParseError::at(
+                    line_number,
+                    line,
+                    line.trim(),
+                    format!("there is no monkey {target}"),
+                ));
+            }
+        }
+    }
+
+    Ok(monkeys)
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        common::assert_answers::<Day11>(EXAMPLE, include_str!("../example_answers.toml"));
+    }
+
+    #[test]
+    fn test_input() {
+        common::assert_answers::<Day11>(Day11::INPUT, Day11::ANSWERS);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let monkey = |items: &str, operation: &str, divisible_by: u64, target: usize| {
+            format!(
+                "Monkey 0:\n  Starting items: {items}\n  Operation: new = old {operation}\n  \
+                 Test: divisible by {divisible_by}\n    If true: throw to monkey {target}\n    \
+                 If false: throw to monkey {target}\n"
+            )
+        };
+
+        // Worry levels too big to square in a u64 still get thrown.
+        let notes = [
+            monkey("4294967296, 18446744073709551615", "* old", 2, 1),
+            monkey("", "* 18446744073709551615", 3, 0),
+        ]
+        .join("\n");
+        let parsed = Day11::parse(&notes).unwrap();
+        assert_eq!(Day11::part_one(&parsed), 40 * 40);
+        assert_eq!(Day11::part_two(&parsed), 20000 * 20000);
+
+        let notes = [
+            monkey("1", "+ 1", 4294967296, 1),
+            monkey("1", "+ 1", 4294967296, 0),
+        ]
+        .join("\n");
+        assert_eq!(
+            Day11::parse(&notes).unwrap_err(),
+            ParseError::new(
+                11,
+                22,
+                "4294967296",
+                "the divisors multiplied together don't fit in a u64"
+            )
+        );
+    }
+
+    /// Distinct primes keep the shared factor (and so the worry levels) small
+    /// enough that squaring them can't overflow.
+    const DIVISORS: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+    fn operation() -> impl Strategy<Value = Operation> {
+        prop_oneof![
+            (1..=10u64).prop_map(|x| Operation::Add(Some(x))),
+            Just(Operation::Add(None)),
+            (1..=20u64).prop_map(|x| Operation::Multiply(Some(x))),
+            Just(Operation::Multiply(None)),
+        ]
+    }
+
+    /// A monkey that throws to the others (but never to itself) out of `count` monkeys.
+    fn monkey(index: usize, count: usize, divisible_by: u64) -> impl Strategy<Value = Monkey> {
+        let other = (1..count).prop_map(move |x| (index + x) % count);
+
+        (
+            prop::collection::vec(0..100u64, 0..6),
+            operation(),
+            other.clone(),
+            other,
+        )
+            .prop_map(move |(items, operation, on_success, on_fail)| Monkey {
+                items: RefCell::new(items),
+                operation,
+                divisible_by,
+                on_success,
+                on_fail,
+                total_inspections: RefCell::new(0),
+            })
+    }
+
+    fn monkeys() -> impl Strategy<Value = Vec<Monkey>> {
+        (2..=8usize)
+            .prop_flat_map(|count| prop::sample::subsequence(DIVISORS.to_vec(), count))
+            .prop_flat_map(|divisors| {
+                let count = divisors.len();
+
+                divisors
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, divisible_by)| monkey(i, count, divisible_by))
+                    .collect::<Vec<_>>()
+            })
+    }
+
+    fn notes(monkeys: &[Monkey]) -> String {
+        monkeys
+            .iter()
+            .enumerate()
+            .map(|(i, monkey)| {
+                let items = monkey
+                    .items
+                    .borrow()
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                let operation = match monkey.operation {
+                    Operation::Add(Some(n)) => format!("+ {n}"),
+                    Operation::Add(None) => "+ old".to_string(),
+                    Operation::Multiply(Some(n)) => format!("* {n}"),
+                    Operation::Multiply(None) => "* old".to_string(),
+                };
+
+                format!(
+                    "Monkey {i}:\n  Starting items: {items}\n  Operation: new = old {operation}\n  \
+                     Test: divisible by {}\n    If true: throw to monkey {}\n    \
+                     If false: throw to monkey {}\n",
+                    monkey.divisible_by, monkey.on_success, monkey.on_fail
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn item_count(monkeys: &[Monkey]) -> usize {
+        monkeys.iter().map(|x| x.items.borrow().len()).sum()
+    }
+
+    proptest! {
+        #[test]
+        fn test_cycles_keep_items(
+            mut monkeys in monkeys(),
+            rounds in 1..=30usize,
+            drop_worry_levels: bool,
+        ) {
+            let item_total = item_count(&monkeys);
+            let shared_factor = monkeys.iter().map(|x| x.divisible_by).product::<u64>();
+
+            for _ in 0..rounds {
+                let inspections_before = monkeys
+                    .iter()
+                    .map(|x| *x.total_inspections.borrow())
+                    .collect::<Vec<u64>>();
+
+                run_cycle(&mut monkeys, drop_worry_levels);
+
+                prop_assert_eq!(item_count(&monkeys), item_total);
+
+                for (monkey, before) in monkeys.iter().zip(inspections_before) {
+                    prop_assert!(*monkey.total_inspections.borrow() >= before);
+                    prop_assert!(monkey.items.borrow().iter().all(|&x| x < shared_factor));
+                }
+            }
+
+            // Every item gets inspected at least once a round, by whoever holds it at the start.
+            let inspections = monkeys
+                .iter()
+                .map(|x| *x.total_inspections.borrow())
+                .sum::<u64>();
+            prop_assert!(inspections >= (item_total * rounds) as u64);
+        }
+
+        #[test]
+        fn test_parsing_notes(monkeys in monkeys()) {
+            let parsed = Day11::parse(&notes(&monkeys)).unwrap();
+
+            prop_assert_eq!(parsed.len(), monkeys.len());
+            prop_assert_eq!(Day11::part_one(&parsed), Day11::part_one(&monkeys));
+
+            for (parsed, monkey) in parsed.iter().zip(&monkeys) {
+                prop_assert_eq!(&parsed.items, &monkey.items);
+                prop_assert_eq!(parsed.divisible_by, monkey.divisible_by);
+                prop_assert_eq!(parsed.on_success, monkey.on_success);
+                prop_assert_eq!(parsed.on_fail, monkey.on_fail);
+            }
+        }
+    }
+}
@@ -0,0 +1,403 @@
+// https://adventofcode.com/2022/day/2
+
+use common::{ParseError, Solution};
+use serde::Deserialize;
+
+/// The rock paper scissors puzzle.
+pub struct Day2;
+
+/// Both readings of the strategy guide. The second column is a hand
+/// in part 1, and the outcome the user should cause in part 2.
+#[derive(Debug)]
+pub struct StrategyGuide {
+    pub enemy_hands: Vec<Hand>,
+    pub user_hands: Vec<Hand>,
+    pub signals: Vec<Outcome>,
+}
+
+/// A hand played in a round of rock paper scissors.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Hand {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+/// From the user's perspective.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Outcome {
+    Win,
+    Draw,
+    Loss,
+}
+
+/// How many points each hand and outcome are worth, so other tournaments
+/// can be scored. Read from TOML like this, where anything left out keeps
+/// the puzzle's score:
+/// ```toml
+/// [hands]
+/// rock = 1
+/// paper = 2
+/// scissors = 3
+///
+/// [outcomes]
+/// win = 6
+/// draw = 3
+/// loss = 0
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScoringRules {
+    pub hands: HandScores,
+    pub outcomes: OutcomeScores,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HandScores {
+    pub rock: u64,
+    pub paper: u64,
+    pub scissors: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OutcomeScores {
+    pub win: u64,
+    pub draw: u64,
+    pub loss: u64,
+}
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const INPUT: &'static str = include_str!("../input.txt");
+    const ANSWERS: &'static str = include_str!("../answers.toml");
+
+    type Parsed = StrategyGuide;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let (enemy_hands, user_hands) = hand_sequences(input)?;
+        let signals = signal_sequence(input)?;
+
+        Ok(StrategyGuide {
+            enemy_hands,
+            user_hands,
+            signals,
+        })
+    }
+
+    fn part_one(guide: &Self::Parsed) -> Self::PartOne {
+        guide
+            .score_hands(&ScoringRules::default())
+            .expect("the puzzle's scores are too small to overflow")
+    }
+
+    fn part_two(guide: &Self::Parsed) -> Self::PartTwo {
+        guide
+            .score_signals(&ScoringRules::default())
+            .expect("the puzzle's scores are too small to overflow")
+    }
+}
+
+impl StrategyGuide {
+    /// The user's total score when the second column is the hand to play,
+    /// or `None` if the rules make it too big for a `u64`.
+    pub fn score_hands(&self, rules: &ScoringRules) -> Option<u64> {
+        let hand_score = total_hand_score(&self.user_hands, rules)?;
+        let outcome_score = total_outcome_score(&self.enemy_hands, &self.user_hands, rules)?;
+        hand_score.checked_add(outcome_score)
+    }
+
+    /// The user's total score when the second column is the outcome to cause,
+    /// or `None` if the rules make it too big for a `u64`.
+    pub fn score_signals(&self, rules: &ScoringRules) -> Option<u64> {
+        let user_hands = signals_to_hands(&self.enemy_hands, &self.signals);
+        let hand_score = total_hand_score(&user_hands, rules)?;
+        let outcome_score = total_outcome_score(&self.enemy_hands, &user_hands, rules)?;
+        hand_score.checked_add(outcome_score)
+    }
+}
+
+impl ScoringRules {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        toml::from_str(text).map_err(|e| e.to_string())
+    }
+}
+
+impl Default for HandScores {
+    fn default() -> Self {
+        Self {
+            rock: 1,
+            paper: 2,
+            scissors: 3,
+        }
+    }
+}
+
+impl Default for OutcomeScores {
+    fn default() -> Self {
+        Self {
+            win: 6,
+            draw: 3,
+            loss: 0,
+        }
+    }
+}
+
+/// Converts the raw line strings into two vectors,
+/// a sequence of enemy hands and a sequence of your suggested hands.
+pub fn hand_sequences(input: &str) -> Result<(Vec<Hand>, Vec<Hand>), ParseError> {
+    let mut enemy_hands = Vec::new();
+    let mut user_hands = Vec::new();
+
+    for (line_number, line) in numbered_lines(input) {
+        let (enemy_column, user_column) = split_columns(line_number, line)?;
+
+        let enemy_hand = match enemy_column {
+            "A" => Hand::Rock,
+            "B" => Hand::Paper,
+            "C" => Hand::Scissors,
+            _ => {
+                return Err(ParseError::at(
+                    line_number,
+                    line,
+                    enemy_column,
+                    "expected A, B or C",
+                ))
+            }
+        };
+        let user_hand = match user_column {
+            "X" => Hand::Rock,
+            "Y" => Hand::Paper,
+            "Z" => Hand::Scissors,
+            _ => {
+                return Err(ParseError::at(
+                    line_number,
+                    line,
+                    user_column,
+                    "expected X, Y or Z",
+                ))
+            }
+        };
+
+        enemy_hands.push(enemy_hand);
+        user_hands.push(user_hand);
+    }
+
+    Ok((enemy_hands, user_hands))
+}
+
+/// Gives each non-empty line its line number.
+fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line))
+        .filter(|(_, line)| !line.is_empty())
+}
+
+/// Splits a line into the enemy's column and the user's column.
+fn split_columns(line_number: usize, line: &str) -> Result<(&str, &str), ParseError> {
+    let mut split = line.split_whitespace();
+
+    let enemy_column = split
+        .next()
+        .ok_or_else(|| ParseError::end_of_line(line_number, line, "expected A, B or C"))?;
+    let user_column = split
+        .next()
+        .ok_or_else(|| ParseError::end_of_line(line_number, line, "expected X, Y or Z"))?;
+
+    Ok((enemy_column, user_column))
+}
+
+/// The score the user gets for the hands they played, or `None` if it
+/// doesn't fit in a `u64`.
+pub fn total_hand_score(user_hands: &[Hand], rules: &ScoringRules) -> Option<u64> {
+    let mut score = 0u64;
+    for hand in user_hands {
+        score = score.checked_add(hand.score(rules))?;
+    }
+    Some(score)
+}
+
+/// The score the user gets for winning, drawing, or losing each round,
+/// or `None` if it doesn't fit in a `u64`.
+pub fn total_outcome_score(
+    enemy_hands: &[Hand],
+    user_hands: &[Hand],
+    rules: &ScoringRules,
+) -> Option<u64> {
+    let mut score = 0u64;
+    for (enemy_hand, user_hand) in enemy_hands.iter().zip(user_hands) {
+        score = score.checked_add(Outcome::from_hands(*enemy_hand, *user_hand).score(rules))?;
+    }
+    Some(score)
+}
+
+/// Tells the user whether they should cause a win,
+/// draw, or loss.
+pub fn signal_sequence(input: &str) -> Result<Vec<Outcome>, ParseError> {
+    let mut signals = Vec::new();
+
+    for (line_number, line) in numbered_lines(input) {
+        let (_, user_column) = split_columns(line_number, line)?;
+
+        let signal = match user_column {
+            "X" => Outcome::Loss,
+            "Y" => Outcome::Draw,
+            "Z" => Outcome::Win,
+            _ => {
+                return Err(ParseError::at(
+                    line_number,
+                    line,
+                    user_column,
+                    "expected X, Y or Z",
+                ))
+            }
+        };
+
+        signals.push(signal);
+    }
+
+    Ok(signals)
+}
+
+/// Picks the hand the user should play to get each signalled outcome.
+pub fn signals_to_hands(enemy_hands: &[Hand], signals: &[Outcome]) -> Vec<Hand> {
+    let mut user_hands = Vec::new();
+
+    for (enemy_hand, signal) in enemy_hands.iter().zip(signals) {
+        let user_hand = match signal {
+            Outcome::Win => enemy_hand.winning_hand(),
+            Outcome::Draw => enemy_hand.draw_hand(),
+            Outcome::Loss => enemy_hand.losing_hand(),
+        };
+        user_hands.push(user_hand);
+    }
+
+    user_hands
+}
+
+impl Hand {
+    /// The score for playing this hand.
+    pub fn score(&self, rules: &ScoringRules) -> u64 {
+        match self {
+            Self::Rock => rules.hands.rock,
+            Self::Paper => rules.hands.paper,
+            Self::Scissors => rules.hands.scissors,
+        }
+    }
+
+    /// The hand that beats this one.
+    pub fn winning_hand(&self) -> Hand {
+        match self {
+            Hand::Rock => Hand::Paper,
+            Hand::Paper => Hand::Scissors,
+            Hand::Scissors => Hand::Rock,
+        }
+    }
+
+    /// The hand that draws against this one.
+    pub fn draw_hand(&self) -> Hand {
+        *self
+    }
+
+    /// The hand that loses to this one.
+    pub fn losing_hand(&self) -> Hand {
+        match self {
+            Hand::Rock => Hand::Scissors,
+            Hand::Paper => Hand::Rock,
+            Hand::Scissors => Hand::Paper,
+        }
+    }
+}
+
+impl Outcome {
+    /// Works out the outcome of a round from the user's perspective.
+    pub fn from_hands(enemy_hand: Hand, user_hand: Hand) -> Self {
+        match enemy_hand {
+            Hand::Rock => match user_hand {
+                Hand::Rock => Outcome::Draw,
+                Hand::Paper => Outcome::Win,
+                Hand::Scissors => Outcome::Loss,
+            },
+            Hand::Paper => match user_hand {
+                Hand::Rock => Outcome::Loss,
+                Hand::Paper => Outcome::Draw,
+                Hand::Scissors => Outcome::Win,
+            },
+            Hand::Scissors => match user_hand {
+                Hand::Rock => Outcome::Win,
+                Hand::Paper => Outcome::Loss,
+                Hand::Scissors => Outcome::Draw,
+            },
+        }
+    }
+
+    /// The score for getting this outcome.
+    pub fn score(&self, rules: &ScoringRules) -> u64 {
+        match self {
+            Outcome::Win => rules.outcomes.win,
+            Outcome::Draw => rules.outcomes.draw,
+            Outcome::Loss => rules.outcomes.loss,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        common::assert_answers::<Day2>(EXAMPLE, include_str!("../example_answers.toml"));
+    }
+
+    #[test]
+    fn test_input() {
+        common::assert_answers::<Day2>(Day2::INPUT, Day2::ANSWERS);
+    }
+
+    #[test]
+    fn test_scoring_rules() {
+        assert_eq!(ScoringRules::parse("").unwrap(), ScoringRules::default());
+        assert_eq!(
+            ScoringRules::parse(include_str!("../rules.toml")).unwrap(),
+            ScoringRules::default()
+        );
+
+        // Only the hands are changed, so the outcomes keep the puzzle's scores.
+        let rules = ScoringRules::parse("[hands]\nrock = 10\npaper = 20\nscissors = 30\n").unwrap();
+        assert_eq!(rules.outcomes, OutcomeScores::default());
+
+        let guide = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(guide.score_hands(&rules), Some(69));
+        assert_eq!(guide.score_signals(&rules), Some(39));
+
+        let rules = ScoringRules::parse("[outcomes]\nwin = 1\ndraw = 0\n").unwrap();
+        assert_eq!(guide.score_hands(&rules), Some(7));
+
+        assert!(ScoringRules::parse("[hands]\nrock = -1\n").is_err());
+        assert!(ScoringRules::parse("[hands]\nlizard = 4\n").is_err());
+        assert!(ScoringRules::parse("[outcome]\nwin = 1\n").is_err());
+    }
+
+    #[test]
+    fn test_overflow() {
+        let rules = ScoringRules::parse("[outcomes]\nwin = 9223372036854775807\n").unwrap();
+        let guide = Day2::parse("A Y\nA Y\n").unwrap();
+        assert_eq!(
+            total_outcome_score(&guide.enemy_hands, &guide.user_hands, &rules),
+            Some(u64::MAX - 1)
+        );
+        assert_eq!(guide.score_hands(&rules), None);
+        assert_eq!(guide.score_signals(&rules), Some(8));
+
+        let guide = Day2::parse("A Z\nA Z\nA Z\n").unwrap();
+        assert_eq!(guide.score_signals(&rules), None);
+    }
+}
@@ -1,179 +1,66 @@
 // https://adventofcode.com/2022/day/2
 
-const RAW_INPUT: &str = include_str!("../input.txt");
-
-#[derive(PartialEq, Clone, Copy)]
-enum Hand {
-    Rock,
-    Paper,
-    Scissors,
-}
-
-/// From the user's perspective.
-#[derive(PartialEq, Clone, Copy)]
-enum Outcome {
-    Win,
-    Draw,
-    Loss,
-}
+use common::{Args, Part};
+use day_2::{Day2, ScoringRules};
 
 fn main() {
-    let (enemy_hands, user_hands) = hand_sequences();
-
-    // Part 1
-    let hand_score = total_hand_score(&user_hands);
-    let outcome_score = total_outcome_score(&enemy_hands, &user_hands);
-    let total_score = hand_score + outcome_score;
-    println!("Total Score: {}", total_score);
-
-    // Part 2
-    let signals = signal_sequence();
-    let user_hands = signals_to_hands(&enemy_hands, &signals);
-    let hand_score = total_hand_score(&user_hands);
-    let outcome_score = total_outcome_score(&enemy_hands, &user_hands);
-    let total_score = hand_score + outcome_score;
-    println!("Total Score Adjusted For Signals: {}", total_score);
-}
-
-/// Converts the raw line strings into two vectors,
-/// a sequence of enemy hands and a sequence of your suggested hands.
-fn hand_sequences() -> (Vec<Hand>, Vec<Hand>) {
-    let lines = RAW_INPUT.lines().filter(|x| !x.is_empty());
-
-    let mut enemy_hands = Vec::new();
-    let mut user_hands = Vec::new();
-
-    for line in lines {
-        let split = line.split_whitespace().collect::<Vec<&str>>();
-
-        let enemy_hand = match split[0] {
-            "A" => Hand::Rock,
-            "B" => Hand::Paper,
-            "C" => Hand::Scissors,
-            _ => panic!(),
-        };
-        let user_hand = match split[1] {
-            "X" => Hand::Rock,
-            "Y" => Hand::Paper,
-            "Z" => Hand::Scissors,
-            _ => panic!(),
-        };
-
-        enemy_hands.push(enemy_hand);
-        user_hands.push(user_hand);
-    }
-
-    (enemy_hands, user_hands)
-}
-
-fn total_hand_score(user_hands: &[Hand]) -> u64 {
-    let mut score = 0;
-    for hand in user_hands {
-        score += hand.score();
-    }
-    score
-}
-
-fn total_outcome_score(enemy_hands: &[Hand], user_hands: &[Hand]) -> u64 {
-    let mut score = 0;
-    for (enemy_hand, user_hand) in enemy_hands.iter().zip(user_hands) {
-        score += Outcome::from_hands(*enemy_hand, *user_hand).score();
+    let (rules, args) = split_rules(std::env::args().skip(1)).unwrap_or_else(|e| exit(&e));
+    let args = Args::parse(args).unwrap_or_else(|e| exit(&e));
+
+    let rules = match rules {
+        Some(path) => std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|x| ScoringRules::parse(&x))
+            .unwrap_or_else(|e| {
+                eprintln!("Could not read the scoring rules in {path}: {e}");
+                std::process::exit(1);
+            }),
+        None => ScoringRules::default(),
+    };
+
+    let input = common::read_or_exit::<Day2>(&args.input);
+    let parsed = common::parse_or_exit::<Day2>(&input);
+
+    if args.includes(Part::One) {
+        match parsed.score_hands(&rules) {
+            Some(score) => println!("Total Score: {score}"),
+            None => too_big(),
+        }
     }
-    score
-}
 
-/// Tells the user whether they should cause a win,
-/// draw, or loss.
-fn signal_sequence() -> Vec<Outcome> {
-    let lines = RAW_INPUT.lines().filter(|x| !x.is_empty());
-
-    let mut signals = Vec::new();
-
-    for line in lines {
-        let signal = match line.split_whitespace().collect::<Vec<&str>>()[1] {
-            "X" => Outcome::Loss,
-            "Y" => Outcome::Draw,
-            "Z" => Outcome::Win,
-            _ => panic!(),
-        };
-
-        signals.push(signal);
+    if args.includes(Part::Two) {
+        match parsed.score_signals(&rules) {
+            Some(score) => println!("Total Score Adjusted For Signals: {score}"),
+            None => too_big(),
+        }
     }
-
-    signals
 }
 
-fn signals_to_hands(enemy_hands: &[Hand], signals: &[Outcome]) -> Vec<Hand> {
-    let mut user_hands = Vec::new();
-
-    for (enemy_hand, signal) in enemy_hands.iter().zip(signals) {
-        let user_hand = match signal {
-            Outcome::Win => enemy_hand.winning_hand(),
-            Outcome::Draw => enemy_hand.draw_hand(),
-            Outcome::Loss => enemy_hand.losing_hand(),
-        };
-        user_hands.push(user_hand);
-    }
-
-    user_hands
+fn too_big() -> ! {
+    eprintln!("The scoring rules make the total score too big to count");
+    std::process::exit(1);
 }
 
-impl Hand {
-    fn score(&self) -> u64 {
-        match self {
-            Self::Rock => 1,
-            Self::Paper => 2,
-            Self::Scissors => 3,
-        }
-    }
-
-    fn winning_hand(&self) -> Hand {
-        match self {
-            Hand::Rock => Hand::Paper,
-            Hand::Paper => Hand::Scissors,
-            Hand::Scissors => Hand::Rock,
+/// Takes `--rules FILE` out of the arguments, since only day 2 has it,
+/// leaving the ones every day accepts.
+fn split_rules(
+    mut args: impl Iterator<Item = String>,
+) -> Result<(Option<String>, Vec<String>), String> {
+    let mut rules = None;
+    let mut rest = Vec::new();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--rules" => rules = Some(args.next().ok_or("`--rules` needs a file")?),
+            _ => rest.push(arg),
         }
     }
 
-    fn draw_hand(&self) -> Hand {
-        *self
-    }
-
-    fn losing_hand(&self) -> Hand {
-        match self {
-            Hand::Rock => Hand::Scissors,
-            Hand::Paper => Hand::Rock,
-            Hand::Scissors => Hand::Paper,
-        }
-    }
+    Ok((rules, rest))
 }
 
-impl Outcome {
-    fn from_hands(enemy_hand: Hand, user_hand: Hand) -> Self {
-        match enemy_hand {
-            Hand::Rock => match user_hand {
-                Hand::Rock => Outcome::Draw,
-                Hand::Paper => Outcome::Win,
-                Hand::Scissors => Outcome::Loss,
-            },
-            Hand::Paper => match user_hand {
-                Hand::Rock => Outcome::Loss,
-                Hand::Paper => Outcome::Draw,
-                Hand::Scissors => Outcome::Win,
-            },
-            Hand::Scissors => match user_hand {
-                Hand::Rock => Outcome::Win,
-                Hand::Paper => Outcome::Loss,
-                Hand::Scissors => Outcome::Draw,
-            },
-        }
-    }
-
-    fn score(&self) -> u64 {
-        match self {
-            Outcome::Win => 6,
-            Outcome::Draw => 3,
-            Outcome::Loss => 0,
-        }
-    }
+fn exit(message: &str) -> ! {
+    eprintln!("{message}");
+    eprintln!("Usage: [--rules FILE] [--part 1|2] [INPUT]");
+    std::process::exit(1);
 }
@@ -0,0 +1,267 @@
+// https://adventofcode.com/2022/day/3/
+
+use common::{OptionalAnswer, ParseError, Solution};
+
+const ASCII_OFFSET: i32 = -96;
+const UPPERCASE_OFFSET: i32 = 26;
+
+/// The rucksack reorganization puzzle.
+pub struct Day3;
+
+/// A line of the input, split into its two compartments.
+#[derive(Debug)]
+pub struct Rucksack {
+    pub first_compartment: Vec<Item>,
+    pub second_compartment: Vec<Item>,
+}
+
+/// An item, identified by its letter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Item {
+    pub inner: char,
+}
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const INPUT: &'static str = include_str!("../input.txt");
+    const ANSWERS: &'static str = include_str!("../answers.toml");
+
+    type Parsed = Vec<Rucksack>;
+    type PartOne = u64;
+    /// Rucksacks that can't be split into groups with a badge have no answer.
+    type PartTwo = OptionalAnswer<u64>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generate_rucksacks(input)
+    }
+
+    fn part_one(rucksacks: &Self::Parsed) -> Self::PartOne {
+        let common_items = find_common_items(rucksacks);
+        sum_priorities(&common_items)
+    }
+
+    fn part_two(rucksacks: &Self::Parsed) -> Self::PartTwo {
+        let badges = find_badges(rucksacks);
+        OptionalAnswer(badges.map(|x| sum_priorities(&x)))
+    }
+}
+
+/// Parses one rucksack per line.
+pub fn generate_rucksacks(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+    let mut rucksacks = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let rucksack = Rucksack::from_string(i + 1, line)?;
+        rucksacks.push(rucksack);
+    }
+    Ok(rucksacks)
+}
+
+/// The item each rucksack has in both compartments.
+pub fn find_common_items(rucksacks: &[Rucksack]) -> Vec<Item> {
+    let mut common_items = Vec::new();
+    for rucksack in rucksacks {
+        common_items.push(rucksack.common_item());
+    }
+    common_items
+}
+
+/// The badge of each group of 3 rucksacks, or `None` if a group is missing
+/// rucksacks or a badge. [`check_groups`] says which.
+pub fn find_badges(rucksacks: &[Rucksack]) -> Option<Vec<Item>> {
+    let mut badges = Vec::new();
+    for group in rucksacks.chunks(3) {
+        match group.len() {
+            3 => badges.push(badge(group)?),
+            _ => return None,
+        }
+    }
+    Some(badges)
+}
+
+/// The item (or badge) that every rucksack in the group has.
+pub fn badge(group: &[Rucksack]) -> Option<Item> {
+    let (first, rest) = group.split_first()?;
+
+    first
+        .items()
+        .find(|item| rest.iter().all(|rucksack| rucksack.contains(item)))
+        .copied()
+}
+
+/// Every 3 lines form a group, which need to have a badge in common for
+/// part 2. Points at the first group that doesn't.
+pub fn check_groups(input: &str, rucksacks: &[Rucksack]) -> Result<(), ParseError> {
+    let lines = input.lines().collect::<Vec<&str>>();
+
+    if !rucksacks.len().is_multiple_of(3) {
+        return Err(ParseError::end_of_line(
+            lines.len(),
+            lines[lines.len() - 1],
+            "expected every group to have 3 rucksacks",
+        ));
+    }
+
+    for (i, group) in rucksacks.chunks(3).enumerate() {
+        if badge(group).is_none() {
+            let last_line_index = i * 3 + 2;
+
+            return Err(ParseError::at(
+                last_line_index + 1,
+                lines[last_line_index],
+                lines[last_line_index],
+                "the group's rucksacks have no item in common",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// The sum of the priorities of the items.
+pub fn sum_priorities(items: &[Item]) -> u64 {
+    items.iter().map(|x| x.priority() as u64).sum()
+}
+
+impl Rucksack {
+    /// Splits the line in half, one half per compartment.
+    pub fn from_string(line_number: usize, string: &str) -> Result<Self, ParseError> {
+        let mut first_compartment = Vec::new();
+        let mut second_compartment = Vec::new();
+
+        if !string.len().is_multiple_of(2) {
+            return Err(ParseError::at(
+                line_number,
+                string,
+                string,
+                "a rucksack needs the same amount of items in both compartments",
+            ));
+        }
+
+        for (i, char) in string.char_indices() {
+            let item = Item::from_char(line_number, string, i, char)?;
+
+            match i < (string.len() / 2) {
+                true => first_compartment.push(item),
+                false => second_compartment.push(item),
+            }
+        }
+
+        let rucksack = Self {
+            first_compartment,
+            second_compartment,
+        };
+
+        // Checking this here means common_item() can't panic later on.
+        if !rucksack
+            .first_compartment
+            .iter()
+            .any(|x| rucksack.second_compartment.contains(x))
+        {
+            return Err(ParseError::at(
+                line_number,
+                string,
+                string,
+                "the compartments have no item in common",
+            ));
+        }
+
+        Ok(rucksack)
+    }
+
+    /// The item that is in both compartments.
+    pub fn common_item(&self) -> Item {
+        for item in &self.first_compartment {
+            if self.second_compartment.contains(item) {
+                return *item;
+            }
+        }
+
+        panic!("Rucksack does not have a common item!")
+    }
+
+    /// The items of both compartments.
+    pub fn items(&self) -> impl Iterator<Item = &Item> {
+        self.first_compartment
+            .iter()
+            .chain(self.second_compartment.iter())
+    }
+
+    /// Whether either compartment has the item.
+    pub fn contains(&self, item: &Item) -> bool {
+        self.first_compartment.contains(item) || self.second_compartment.contains(item)
+    }
+}
+
+impl Item {
+    /// `index` is the byte index of the item within `line`.
+    pub fn from_char(
+        line_number: usize,
+        line: &str,
+        index: usize,
+        char: char,
+    ) -> Result<Self, ParseError> {
+        if !char.is_ascii_alphabetic() {
+            let token = &line[index..index + char.len_utf8()];
+            return Err(ParseError::at(
+                line_number,
+                line,
+                token,
+                "expected an item from a-z or A-Z",
+            ));
+        }
+
+        Ok(Item { inner: char })
+    }
+
+    /// a-z have priorities 1 to 26, and A-Z have 27 to 52.
+    pub fn priority(&self) -> u8 {
+        match self.inner.is_uppercase() {
+            true => {
+                (self.inner.to_lowercase().next().unwrap() as i32 + ASCII_OFFSET + UPPERCASE_OFFSET)
+                    as u8
+            }
+            false => (self.inner.to_lowercase().next().unwrap() as i32 + ASCII_OFFSET) as u8,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        common::assert_answers::<Day3>(EXAMPLE, include_str!("../example_answers.toml"));
+    }
+
+    #[test]
+    fn test_input() {
+        common::assert_answers::<Day3>(Day3::INPUT, Day3::ANSWERS);
+    }
+
+    #[test]
+    fn test_groups() {
+        // A single rucksack is fine for part 1, but isn't a group for part 2.
+        let input = "vJrwpWtwJgWrhcsFMMfFFhFp";
+        let parsed = Day3::parse(input).unwrap();
+        assert_eq!(Day3::part_one(&parsed), 16);
+        assert_eq!(Day3::part_two(&parsed), OptionalAnswer(None));
+        assert_eq!(
+            check_groups(input, &parsed).unwrap_err().message,
+            "expected every group to have 3 rucksacks"
+        );
+
+        let input = "aa\nbb\ncc\n";
+        let parsed = Day3::parse(input).unwrap();
+        assert_eq!(Day3::part_two(&parsed), OptionalAnswer(None));
+        assert_eq!(
+            check_groups(input, &parsed).unwrap_err(),
+            ParseError::new(3, 1, "cc", "the group's rucksacks have no item in common")
+        );
+
+        let parsed = Day3::parse(EXAMPLE).unwrap();
+        assert!(check_groups(EXAMPLE, &parsed).is_ok());
+    }
+}
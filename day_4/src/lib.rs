@@ -0,0 +1,151 @@
+// https://adventofcode.com/2022/day/4/
+
+use std::ops::RangeInclusive;
+
+use common::{parse_number, ParseError, Solution};
+
+/// The camp cleanup puzzle.
+pub struct Day4;
+
+/// The sections assigned to a pair of elves.
+#[derive(Debug)]
+pub struct AssignmentPair {
+    pub first_elf: RangeInclusive<u64>,
+    pub second_elf: RangeInclusive<u64>,
+}
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const INPUT: &'static str = include_str!("../input.txt");
+    const ANSWERS: &'static str = include_str!("../answers.toml");
+
+    type Parsed = Vec<AssignmentPair>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generate_assignment_pairs(input)
+    }
+
+    fn part_one(all_assignments: &Self::Parsed) -> Self::PartOne {
+        amount_of_fully_overlapping_pairs(all_assignments)
+    }
+
+    fn part_two(all_assignments: &Self::Parsed) -> Self::PartTwo {
+        amount_of_partially_overlapping_pairs(all_assignments)
+    }
+}
+
+/// Parses one pair of assignments per line.
+pub fn generate_assignment_pairs(input: &str) -> Result<Vec<AssignmentPair>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| AssignmentPair::from_string(i + 1, line))
+        .collect()
+}
+
+/// The amount of pairs where one assignment contains the other.
+pub fn amount_of_fully_overlapping_pairs(all_assignments: &[AssignmentPair]) -> u64 {
+    all_assignments
+        .iter()
+        .filter(|x| x.fully_overlaps())
+        .count() as u64
+}
+
+/// The amount of pairs whose assignments overlap at all.
+pub fn amount_of_partially_overlapping_pairs(all_assignments: &[AssignmentPair]) -> u64 {
+    all_assignments
+        .iter()
+        .filter(|x| x.partially_overlaps())
+        .count() as u64
+}
+
+impl AssignmentPair {
+    /// Parses a line like `2-4,6-8`.
+    pub fn from_string(line_number: usize, input: &str) -> Result<Self, ParseError> {
+        let (first_raw, second_raw) = input.split_once(',').ok_or_else(|| {
+            ParseError::end_of_line(line_number, input, "expected two assignments split by `,`")
+        })?;
+
+        let first_elf = Self::raw_assignment_to_range(line_number, input, first_raw)?;
+        let second_elf = Self::raw_assignment_to_range(line_number, input, second_raw)?;
+
+        Ok(Self {
+            first_elf,
+            second_elf,
+        })
+    }
+
+    /// Whether one elf's sections contain all of the other's.
+    pub fn fully_overlaps(&self) -> bool {
+        if self.first_elf.contains(self.second_elf.start())
+            && self.first_elf.contains(self.second_elf.end())
+        {
+            return true;
+        }
+
+        if self.second_elf.contains(self.first_elf.start())
+            && self.second_elf.contains(self.first_elf.end())
+        {
+            return true;
+        }
+
+        false
+    }
+
+    /// Whether the elves share any sections.
+    pub fn partially_overlaps(&self) -> bool {
+        if self.first_elf.contains(self.second_elf.start())
+            || self.first_elf.contains(self.second_elf.end())
+        {
+            return true;
+        }
+
+        if self.second_elf.contains(self.first_elf.start())
+            || self.second_elf.contains(self.first_elf.end())
+        {
+            return true;
+        }
+
+        false
+    }
+
+    /// `raw_assignment` is a slice of `line`, which is only used for error reporting.
+    pub fn raw_assignment_to_range(
+        line_number: usize,
+        line: &str,
+        raw_assignment: &str,
+    ) -> Result<RangeInclusive<u64>, ParseError> {
+        let (raw_start, raw_end) = raw_assignment.split_once('-').ok_or_else(|| {
+            ParseError::at(
+                line_number,
+                line,
+                raw_assignment,
+                "expected a range of sections like `2-4`",
+            )
+        })?;
+
+        let start = parse_number(line_number, line, raw_start)?;
+        let end = parse_number(line_number, line, raw_end)?;
+
+        Ok(start..=end)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        common::assert_answers::<Day4>(EXAMPLE, include_str!("../example_answers.toml"));
+    }
+
+    #[test]
+    fn test_input() {
+        common::assert_answers::<Day4>(Day4::INPUT, Day4::ANSWERS);
+    }
+}
@@ -0,0 +1,469 @@
+// https://adventofcode.com/2022/day/5
+
+use std::str::Lines;
+
+use common::{parse_number, ParseError, Solution};
+use tracing::{debug, debug_span};
+
+/// The supply stacks puzzle.
+pub struct Day5;
+
+/// The stacks of crates, bottom crate first.
+#[derive(Debug, Clone)]
+pub struct Dock {
+    /// Each stack contains a vector of chars which represent
+    /// the creates. The top of the crate stack is represented at
+    /// the end of these vectors.
+    pub stacks: Vec<Vec<char>>,
+}
+
+/// A single `move 1 from 2 to 1` line.
+#[derive(Debug)]
+pub struct Instruction {
+    pub amount: u64,
+    pub from_index: usize,
+    pub to_index: usize,
+}
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const INPUT: &'static str = include_str!("../input.txt");
+    const ANSWERS: &'static str = include_str!("../answers.toml");
+
+    type Parsed = (Dock, Vec<Instruction>);
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let lines = input.lines();
+
+        let (stack_amount, stack_details_line_index) =
+            find_stack_numbers_line_details(lines.clone())?;
+        let dock = Dock::new(lines.clone(), stack_amount, stack_details_line_index)?;
+        let instructions = generate_instructions(lines, stack_details_line_index, &dock)?;
+
+        Ok((dock, instructions))
+    }
+
+    fn part_one((dock, instructions): &Self::Parsed) -> Self::PartOne {
+        let mut dock = dock.clone();
+
+        dock.process_instructions(instructions);
+        dock.top_crates_string()
+    }
+
+    fn part_two((dock, instructions): &Self::Parsed) -> Self::PartTwo {
+        let mut dock = dock.clone();
+
+        dock.process_instructions_upgraded(instructions);
+        dock.top_crates_string()
+    }
+}
+
+/// Returns the amount of stacks, and the index of the line it was found on.
+pub fn find_stack_numbers_line_details(lines: Lines) -> Result<(u64, usize), ParseError> {
+    for (i, line) in lines.into_iter().enumerate() {
+        let split = line.split_whitespace();
+        let parsed_numbers_count = split.filter(|x| x.parse::<u64>().is_ok()).count() as u64;
+
+        if parsed_numbers_count != 0 {
+            return Ok((parsed_numbers_count, i));
+        }
+    }
+
+    Err(ParseError::new(
+        1,
+        1,
+        "",
+        "expected a line numbering the stacks",
+    ))
+}
+
+/// Also makes sure every instruction can be carried out on the dock,
+/// so processing them later on can't panic.
+pub fn generate_instructions(
+    lines: Lines,
+    stack_details_line_index: usize,
+    dock: &Dock,
+) -> Result<Vec<Instruction>, ParseError> {
+    let mut stack_heights = dock.stacks.iter().map(|x| x.len()).collect::<Vec<usize>>();
+    let mut instructions = Vec::new();
+
+    // We add 1 to the index so it gives us the line number of the details line.
+    // We then add another one to skip the blank line.
+    let skipped = stack_details_line_index + 1 + 1;
+
+    for (i, line) in lines.enumerate().skip(skipped) {
+        let line_number = i + 1;
+        let instruction = Instruction::new(line_number, line, stack_heights.len())?;
+
+        let amount = instruction.amount as usize;
+        let available = stack_heights[instruction.from_index];
+
+        if available < amount {
+            return Err(ParseError::at(
+                line_number,
+                line,
+                line,
+                format!(
+                    "stack {} only has {} crates to move at this point",
+                    instruction.from_index + 1,
+                    available
+                ),
+            ));
+        }
+
+        stack_heights[instruction.from_index] -= amount;
+        stack_heights[instruction.to_index] += amount;
+
+        instructions.push(instruction);
+    }
+
+    Ok(instructions)
+}
+
+impl Dock {
+    /// Builds the dock from the drawing of the starting stacks.
+    pub fn new(
+        lines: Lines,
+        stack_amount: u64,
+        stack_details_line_index: usize,
+    ) -> Result<Self, ParseError> {
+        let stacks = Self::initial_stacks(lines, stack_amount, stack_details_line_index)?;
+        Ok(Self { stacks })
+    }
+
+    /// Moves the crates one at a time.
+    pub fn process_instructions(&mut self, instructions: &[Instruction]) {
+        let _span = debug_span!("process_instructions").entered();
+
+        for instruction in instructions {
+            for _ in 0..instruction.amount {
+                let c = self.stacks[instruction.from_index].pop().unwrap();
+                self.stacks[instruction.to_index].push(c);
+            }
+
+            self.trace_move(instruction);
+        }
+    }
+
+    /// Moves the crates all at once, retaining their order.
+    pub fn process_instructions_upgraded(&mut self, instructions: &[Instruction]) {
+        let _span = debug_span!("process_instructions_upgraded").entered();
+
+        for instruction in instructions {
+            let mut crates = Vec::new();
+
+            for _ in 0..instruction.amount {
+                let c = self.stacks[instruction.from_index].pop().unwrap();
+                crates.push(c);
+            }
+
+            crates.reverse();
+
+            for c in crates {
+                self.stacks[instruction.to_index].push(c);
+            }
+
+            self.trace_move(instruction);
+        }
+    }
+
+    fn trace_move(&self, instruction: &Instruction) {
+        debug!(
+            amount = instruction.amount,
+            from = instruction.from_index + 1,
+            to = instruction.to_index + 1,
+            top_crates = %self.top_crates_string(),
+            "moved crates"
+        );
+    }
+
+    /// Stacks that ended up empty are skipped.
+    pub fn top_crates_string(&self) -> String {
+        let mut sum_string = String::new();
+        for stack in &self.stacks {
+            if let Some(c) = stack.last() {
+                sum_string.push(*c);
+            }
+        }
+        sum_string
+    }
+
+    fn initial_stacks(
+        lines: Lines,
+        stack_amount: u64,
+        stack_details_line_index: usize,
+    ) -> Result<Vec<Vec<char>>, ParseError> {
+        // We can use the stack details line index since it is the same as the previous index + 1
+        let relevant_lines = lines.take(stack_details_line_index);
+
+        // These crates are horizontal but we need to stack them up.
+        //
+        let crate_rows = relevant_lines
+            .enumerate()
+            .map(|(i, line)| Self::crate_line_to_chars(i + 1, line))
+            .collect::<Result<Vec<Vec<Option<char>>>, ParseError>>()?;
+
+        let mut stacks = Vec::new();
+        for _ in 0..stack_amount {
+            stacks.push(Vec::new());
+        }
+
+        for (row_index, row) in crate_rows.iter().enumerate().rev() {
+            for (i, possible_char) in row.iter().enumerate() {
+                if let Some(c) = possible_char {
+                    let stack: &mut Vec<char> = stacks.get_mut(i).ok_or_else(|| {
+                        ParseError::new(
+                            row_index + 1,
+                            i * 4 + 1,
+                            format!("[{c}]"),
+                            format!("there are only {stack_amount} stacks"),
+                        )
+                    })?;
+
+                    stack.push(*c);
+                }
+            }
+        }
+
+        Ok(stacks)
+    }
+
+    /// Reads a row of the drawing. Each stack gets `Some` crate, or `None` if
+    /// the stack doesn't reach up to this row.
+    pub fn crate_line_to_chars(
+        line_number: usize,
+        crate_line: &str,
+    ) -> Result<Vec<Option<char>>, ParseError> {
+        let raw_line_chars = crate_line.chars().collect::<Vec<char>>();
+
+        // Each crate in the text is 3 characters long, with a space inbetween.
+        // We cannot split on the whitespace as not every stack has non-whitespace
+        // characters at the top.
+        raw_line_chars
+            .chunks(4)
+            .enumerate()
+            .map(|(i, x)| {
+                let crate_string = x.iter().take(3).collect::<String>();
+
+                Self::crate_string_to_char(&crate_string).ok_or_else(|| {
+                    ParseError::new(
+                        line_number,
+                        i * 4 + 1,
+                        crate_string,
+                        "expected a crate like `[A]`, or spaces",
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Returns `None` if the string isn't a crate or blank space,
+    /// and `Some(None)` if there is no crate.
+    fn crate_string_to_char(crate_string: &str) -> Option<Option<char>> {
+        if crate_string.split_whitespace().count() == 0 {
+            return Some(None);
+        }
+
+        match crate_string.chars().collect::<Vec<char>>()[..] {
+            ['[', c, ']'] => Some(Some(c)),
+            _ => None,
+        }
+    }
+}
+
+impl Instruction {
+    /// Follows the structure: move <amount> from <stack> to <stack>
+    pub fn new(line_number: usize, line: &str, stack_amount: usize) -> Result<Self, ParseError> {
+        let mut split = line.split_whitespace().skip(1).step_by(2);
+
+        let mut next_number = |what: &str| match split.next() {
+            Some(token) => parse_number::<usize>(line_number, line, token).map(|x| (x, token)),
+            None => Err(ParseError::end_of_line(
+                line_number,
+                line,
+                format!("expected {what}"),
+            )),
+        };
+
+        let (amount, _) = next_number("the amount of crates to move")?;
+        let (from_number, from_token) = next_number("the stack to move crates from")?;
+        let (to_number, to_token) = next_number("the stack to move crates to")?;
+
+        // We subtract one from here as we're representing the index, not stack number.
+        let stack_index = |number: usize, token: &str| {
+            if number == 0 || number > stack_amount {
+                return Err(ParseError::at(
+                    line_number,
+                    line,
+                    token,
+                    format!("expected a stack from 1 to {stack_amount}"),
+                ));
+            }
+
+            Ok(number - 1)
+        };
+
+        let from_index = stack_index(from_number, from_token)?;
+        let to_index = stack_index(to_number, to_token)?;
+
+        Ok(Self {
+            amount: amount as u64,
+            from_index,
+            to_index,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        common::assert_answers::<Day5>(EXAMPLE, include_str!("../example_answers.toml"));
+    }
+
+    #[test]
+    fn test_input() {
+        common::assert_answers::<Day5>(Day5::INPUT, Day5::ANSWERS);
+    }
+
+    fn dock() -> impl Strategy<Value = Dock> {
+        let stack = prop::collection::vec(prop::char::range('A', 'Z'), 0..8);
+        prop::collection::vec(stack, 1..=9).prop_map(|stacks| Dock { stacks })
+    }
+
+    /// Turns random numbers into instructions that only ever move crates that are there,
+    /// and never more than `max_amount` at once.
+    fn valid_instructions(
+        dock: &Dock,
+        moves: &[(usize, usize, usize)],
+        max_amount: usize,
+    ) -> Vec<Instruction> {
+        let mut stack_heights = dock.stacks.iter().map(|x| x.len()).collect::<Vec<usize>>();
+        let mut instructions = Vec::new();
+
+        for (from, to, amount) in moves {
+            let from_index = from % stack_heights.len();
+            let to_index = to % stack_heights.len();
+            let amount = (amount % (stack_heights[from_index] + 1)).min(max_amount);
+
+            stack_heights[from_index] -= amount;
+            stack_heights[to_index] += amount;
+
+            instructions.push(Instruction {
+                amount: amount as u64,
+                from_index,
+                to_index,
+            });
+        }
+
+        instructions
+    }
+
+    /// Draws the dock and instructions the same way the puzzle input does.
+    fn puzzle_text(dock: &Dock, instructions: &[Instruction]) -> String {
+        let height = dock.stacks.iter().map(|x| x.len()).max().unwrap_or(0);
+        let mut text = String::new();
+
+        for level in (0..height).rev() {
+            let row = dock
+                .stacks
+                .iter()
+                .map(|x| match x.get(level) {
+                    Some(c) => format!("[{c}]"),
+                    None => "   ".to_string(),
+                })
+                .collect::<Vec<String>>();
+
+            text += &format!("{}\n", row.join(" "));
+        }
+
+        let numbers = (1..=dock.stacks.len())
+            .map(|x| format!(" {x} "))
+            .collect::<Vec<String>>();
+
+        text += &format!("{}\n\n", numbers.join(" "));
+
+        for instruction in instructions {
+            text += &format!(
+                "move {} from {} to {}\n",
+                instruction.amount,
+                instruction.from_index + 1,
+                instruction.to_index + 1
+            );
+        }
+
+        text
+    }
+
+    fn sorted_crates(dock: &Dock) -> Vec<char> {
+        let mut crates = dock.stacks.concat();
+        crates.sort();
+        crates
+    }
+
+    proptest! {
+        #[test]
+        fn test_crates_are_conserved(
+            dock in dock(),
+            moves in prop::collection::vec(any::<(usize, usize, usize)>(), 0..50),
+        ) {
+            let instructions = valid_instructions(&dock, &moves, usize::MAX);
+
+            let mut crate_mover_9000 = dock.clone();
+            crate_mover_9000.process_instructions(&instructions);
+
+            let mut crate_mover_9001 = dock.clone();
+            crate_mover_9001.process_instructions_upgraded(&instructions);
+
+            prop_assert_eq!(sorted_crates(&crate_mover_9000), sorted_crates(&dock));
+            prop_assert_eq!(sorted_crates(&crate_mover_9001), sorted_crates(&dock));
+
+            // Both cranes move the same amount of crates, just in a different order.
+            let heights = |dock: &Dock| dock.stacks.iter().map(|x| x.len()).collect::<Vec<usize>>();
+            prop_assert_eq!(heights(&crate_mover_9000), heights(&crate_mover_9001));
+        }
+
+        #[test]
+        fn test_single_crate_moves_match(
+            dock in dock(),
+            moves in prop::collection::vec(any::<(usize, usize, usize)>(), 0..50),
+        ) {
+            let instructions = valid_instructions(&dock, &moves, 1);
+
+            let mut crate_mover_9000 = dock.clone();
+            crate_mover_9000.process_instructions(&instructions);
+
+            let mut crate_mover_9001 = dock;
+            crate_mover_9001.process_instructions_upgraded(&instructions);
+
+            prop_assert_eq!(crate_mover_9000.stacks, crate_mover_9001.stacks);
+        }
+
+        #[test]
+        fn test_parsing_docks(
+            dock in dock(),
+            moves in prop::collection::vec(any::<(usize, usize, usize)>(), 0..50),
+        ) {
+            let instructions = valid_instructions(&dock, &moves, usize::MAX);
+            let (parsed_dock, parsed_instructions) =
+                Day5::parse(&puzzle_text(&dock, &instructions)).unwrap();
+
+            prop_assert_eq!(&parsed_dock.stacks, &dock.stacks);
+            prop_assert_eq!(parsed_instructions.len(), instructions.len());
+
+            for (parsed, instruction) in parsed_instructions.iter().zip(&instructions) {
+                prop_assert_eq!(parsed.amount, instruction.amount);
+                prop_assert_eq!(parsed.from_index, instruction.from_index);
+                prop_assert_eq!(parsed.to_index, instruction.to_index);
+            }
+        }
+    }
+}
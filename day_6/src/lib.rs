@@ -0,0 +1,104 @@
+// https://adventofcode.com/2022/day/6
+
+use std::collections::HashSet;
+
+use common::{OptionalAnswer, ParseError, Solution};
+
+/// The size of the start-of-packet marker.
+pub const PART_1_MARKER_SIZE: usize = 4;
+/// The size of the start-of-message marker.
+pub const PART_2_MARKER_SIZE: usize = 14;
+
+/// The tuning trouble puzzle.
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const INPUT: &'static str = include_str!("../input.txt");
+    const ANSWERS: &'static str = include_str!("../answers.toml");
+
+    type Parsed = Vec<char>;
+    /// Datastreams without a marker have no answer.
+    type PartOne = OptionalAnswer<u64>;
+    type PartTwo = OptionalAnswer<u64>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(input.trim_end().chars().collect())
+    }
+
+    fn part_one(chars: &Self::Parsed) -> Self::PartOne {
+        OptionalAnswer(first_marker_char_number(chars, PART_1_MARKER_SIZE))
+    }
+
+    fn part_two(chars: &Self::Parsed) -> Self::PartTwo {
+        OptionalAnswer(first_marker_char_number(chars, PART_2_MARKER_SIZE))
+    }
+}
+
+/// Returns the number of the last character that completes the marker.
+/// Note that this does not return the index, but the character number.
+pub fn first_marker_char_number(chars: &[char], marker_len: usize) -> Option<u64> {
+    for (i, slice) in chars.windows(marker_len).enumerate() {
+        if all_unique(slice) {
+            return Some((i + marker_len) as u64);
+        }
+    }
+
+    None
+}
+
+/// Whether no character shows up twice.
+pub fn all_unique(chars: &[char]) -> bool {
+    let mut seen = HashSet::new();
+
+    for char in chars {
+        seen.insert(char);
+    }
+
+    seen.len() == chars.len()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        common::assert_answers::<Day6>(EXAMPLE, include_str!("../example_answers.toml"));
+    }
+
+    #[test]
+    fn test_other_examples() {
+        let examples = [
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+        ];
+
+        for (datastream, part_one, part_two) in examples {
+            let parsed = Day6::parse(datastream).unwrap();
+            assert_eq!(Day6::part_one(&parsed), OptionalAnswer(Some(part_one)));
+            assert_eq!(Day6::part_two(&parsed), OptionalAnswer(Some(part_two)));
+        }
+    }
+
+    #[test]
+    fn test_missing_markers() {
+        // Part 1 has an answer even when part 2 doesn't.
+        let parsed = Day6::parse("abcd").unwrap();
+        assert_eq!(Day6::part_one(&parsed), OptionalAnswer(Some(4)));
+        assert_eq!(Day6::part_two(&parsed), OptionalAnswer(None));
+        assert_eq!(Day6::part_two(&parsed).to_string(), "no answer");
+
+        let parsed = Day6::parse("").unwrap();
+        assert_eq!(Day6::part_one(&parsed), OptionalAnswer(None));
+    }
+
+    #[test]
+    fn test_input() {
+        common::assert_answers::<Day6>(Day6::INPUT, Day6::ANSWERS);
+    }
+}
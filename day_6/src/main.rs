@@ -1,50 +1,27 @@
 // https://adventofcode.com/2022/day/6
 
-use std::collections::HashSet;
-
-const INPUT: &str = include_str!("../input.txt");
-const PART_1_MARKER_SIZE: usize = 4;
-const PART_2_MARKER_SIZE: usize = 14;
+use common::{Args, OptionalAnswer, Part, Solution};
+use day_6::{Day6, PART_1_MARKER_SIZE, PART_2_MARKER_SIZE};
 
 fn main() {
-    let chars = INPUT.chars().collect::<Vec<char>>();
-
-    // Part 1
-    let first_marker = first_marker_char_number(&chars, PART_1_MARKER_SIZE);
-
-    println!(
-        "First Marker (size {}) Completed At Character Number: {}",
-        PART_1_MARKER_SIZE, first_marker
-    );
-
-    // Part 1
-    let first_marker = first_marker_char_number(&chars, PART_2_MARKER_SIZE);
-
-    println!(
-        "First Marker (size {}) Completed At Character Number: {}",
-        PART_2_MARKER_SIZE, first_marker
-    );
-}
+    let args = Args::from_env();
+    let input = common::read_or_exit::<Day6>(&args.input);
+    let parsed = common::parse_or_exit::<Day6>(&input);
 
-/// Returns the number of the last character that completes the marker.
-/// Note that this does not return the index, but the character number.
-fn first_marker_char_number(chars: &[char], marker_len: usize) -> u64 {
-    for i in 0..chars.len() {
-        let slice = &chars[i..i + marker_len];
-        if all_unique(slice) {
-            return (i + marker_len) as u64;
-        }
+    if args.includes(Part::One) {
+        print_marker(PART_1_MARKER_SIZE, Day6::part_one(&parsed));
     }
 
-    panic!("No marker found!")
+    if args.includes(Part::Two) {
+        print_marker(PART_2_MARKER_SIZE, Day6::part_two(&parsed));
+    }
 }
 
-fn all_unique(chars: &[char]) -> bool {
-    let mut seen = HashSet::new();
-
-    for char in chars {
-        seen.insert(char);
+fn print_marker(size: usize, answer: OptionalAnswer<u64>) {
+    match answer.0 {
+        Some(number) => {
+            println!("First Marker (size {size}) Completed At Character Number: {number}")
+        }
+        None => println!("No Marker (size {size}) In The Datastream"),
     }
-
-    seen.len() == chars.len()
 }
@@ -0,0 +1,545 @@
+// Thank you for https://rust-leipzig.github.io/architecture/2016/12/20/idiomatic-trees-in-rust/
+// showing how to make tree structures in Rust look nice.
+
+/*
+A rant:
+this is probably the messiest aoc ive done, but i stopped caring an hour ago
+im a little over 3 hours deep now, the code lost all elegant about an hour in
+lesson learned: tree structures suck in rust
+*/
+
+use std::collections::HashMap;
+
+use common::{parse_number, ParseError, Solution};
+use tracing::{debug, debug_span, trace};
+
+/// The no space left on device puzzle.
+pub struct Day7;
+
+/// An index into the [`FileSystem`].
+pub type INodeId = usize;
+
+/// A command from the terminal output, along with the line it was on.
+#[derive(Debug)]
+pub enum Command {
+    Cd {
+        argument: String,
+        line_number: usize,
+    },
+    Ls {
+        output: Vec<String>,
+        line_number: usize,
+    },
+}
+
+/// A memory arena so we don't have to mess with yucky lifetimes.
+/// This works because everything in the memory arena has the same lifetime.
+/// https://en.wikipedia.org/wiki/Region-based_memory_management
+///
+/// BUT, it's nice to just name it FileSystem here since that's
+/// what we're using it as.
+#[derive(Debug, Default)]
+pub struct FileSystem {
+    pub inodes: Vec<INode>,
+}
+
+/// What an inode is. Paths start from the root, and each part ends with a `/`.
+#[derive(Debug, Clone)]
+pub enum INodeDetails {
+    Directory {
+        path: String,
+    },
+    /// Contains the file size.
+    File {
+        path: String,
+        size: u64,
+    },
+}
+
+/// A file or directory in the [`FileSystem`].
+#[derive(Debug, Clone)]
+pub struct INode {
+    pub parent: Option<INodeId>,
+    pub children: Vec<INodeId>,
+
+    pub data: INodeDetails,
+}
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const INPUT: &'static str = include_str!("../input.txt");
+    const ANSWERS: &'static str = include_str!("../answers.toml");
+
+    type Parsed = FileSystem;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let commands = parse_commands(input)?;
+        FileSystem::from_commands(&commands)
+    }
+
+    fn part_one(file_system: &Self::Parsed) -> Self::PartOne {
+        part_one(file_system)
+    }
+
+    fn part_two(file_system: &Self::Parsed) -> Self::PartTwo {
+        part_two(file_system)
+    }
+}
+
+fn part_one(file_system: &FileSystem) -> u64 {
+    let map = file_system.dir_size_map();
+
+    let mut sum_above_size_100000 = 0;
+    for size in map.values() {
+        if *size <= 100000 {
+            sum_above_size_100000 += size;
+        }
+    }
+
+    sum_above_size_100000
+}
+
+fn part_two(file_system: &FileSystem) -> u64 {
+    let map = file_system.dir_size_map();
+
+    // The sizes can be anything a u64 holds, so the differences need an i128.
+    let root_size = file_system.size_of_inode(0) as i128;
+    let total_size = 70_000_000;
+    let need_available = 30_000_000;
+    let must_delete_file_of_at_least_this_size = (total_size - need_available - root_size).abs();
+
+    let mut smallest_size_above = total_size;
+    for (_, size) in map {
+        let difference = size as i128 - must_delete_file_of_at_least_this_size;
+
+        if difference <= 0 {
+            continue;
+        }
+
+        let leading_difference = smallest_size_above - must_delete_file_of_at_least_this_size;
+
+        if difference < leading_difference {
+            smallest_size_above = size as i128;
+        }
+    }
+
+    // Either the size of the disk or the size of a directory.
+    smallest_size_above as u64
+}
+
+/// Groups the terminal output into commands, with the output of each `ls`.
+pub fn parse_commands(input: &str) -> Result<Vec<Command>, ParseError> {
+    let mut commands = Vec::new();
+
+    // For the ls command, we use this buffer to store the output
+    let mut output_buffer: Vec<String> = Vec::new();
+
+    // The line number and text of the earliest output line in the buffer,
+    // in case it turns out there's no command it belongs to.
+    let mut first_output_line = None;
+
+    // We go throught the commands backwards to make outputs easier.
+    let lines = input.lines().collect::<Vec<&str>>();
+
+    for (i, line) in lines.into_iter().enumerate().rev() {
+        let line_number = i + 1;
+
+        // Command line follows structure: $ cm path
+        // Output line follows structure: size filename
+        let split = line.split_whitespace().collect::<Vec<&str>>();
+
+        if split.is_empty() {
+            return Err(ParseError::new(
+                line_number,
+                1,
+                "",
+                "expected a command or the output of `ls`",
+            ));
+        }
+
+        let is_command_line = split[0] == "$";
+
+        match is_command_line {
+            true => {
+                let command_name = split.get(1).ok_or_else(|| {
+                    ParseError::end_of_line(line_number, line, "expected `cd` or `ls`")
+                })?;
+
+                let command = match *command_name {
+                    "cd" => Command::Cd {
+                        argument: split
+                            .get(2)
+                            .ok_or_else(|| {
+                                ParseError::end_of_line(line_number, line, "expected a directory")
+                            })?
+                            .to_string(),
+                        line_number,
+                    },
+                    "ls" => {
+                        let command = Command::Ls {
+                            output: output_buffer.clone(),
+                            line_number,
+                        };
+
+                        output_buffer.clear();
+                        first_output_line = None;
+
+                        command
+                    }
+                    _ => {
+                        return Err(ParseError::at(
+                            line_number,
+                            line,
+                            command_name,
+                            "expected `cd` or `ls`",
+                        ))
+                    }
+                };
+
+                commands.push(command);
+            }
+            false => {
+                if split[0] != "dir" {
+                    parse_number::<u64>(line_number, line, split[0])?;
+                }
+
+                if split.len() < 2 {
+                    return Err(ParseError::end_of_line(
+                        line_number,
+                        line,
+                        "expected a name",
+                    ));
+                }
+
+                let joined = split.join(" ");
+                output_buffer.push(joined);
+                first_output_line = Some((line_number, line));
+            }
+        };
+    }
+
+    if let Some((line_number, line)) = first_output_line {
+        return Err(ParseError::at(
+            line_number,
+            line,
+            line,
+            "output came before any `ls` command",
+        ));
+    }
+
+    // We reverse the commands list since we parsed it backwards.
+    commands.reverse();
+
+    Ok(commands)
+}
+
+impl FileSystem {
+    /// An empty file system.
+    pub fn new() -> Self {
+        Self { inodes: Vec::new() }
+    }
+
+    /// Builds the file system by following along with the commands.
+    pub fn from_commands(command: &[Command]) -> Result<Self, ParseError> {
+        let _span = debug_span!("from_commands").entered();
+        let mut file_system = FileSystem::new();
+
+        let mut current_path = String::new();
+        let mut current_parent: Option<INodeId> = None;
+
+        // Every directory is at most this big, so checking it keeps their sizes from overflowing.
+        let mut total_size = 0u64;
+
+        for command in command {
+            match command {
+                Command::Cd {
+                    argument,
+                    line_number,
+                } => match current_parent {
+                    Some(_) => {
+                        current_path = match argument.as_str() {
+                            "/" => "/".to_owned(),
+                            ".." => {
+                                let mut split = current_path
+                                    .split('/')
+                                    .filter(|x| !x.is_empty())
+                                    .collect::<Vec<&str>>();
+
+                                split.pop();
+
+                                let joined = split.join("/");
+
+                                match joined.is_empty() {
+                                    true => "/".to_owned(),
+                                    false => {
+                                        format!("/{}/", joined)
+                                    }
+                                }
+                            }
+                            _ => format!("{}{}/", current_path, argument),
+                        };
+
+                        let id = file_system.id_from_path(&current_path).ok_or_else(|| {
+                            ParseError::new(
+                                *line_number,
+                                1,
+                                format!("$ cd {argument}"),
+                                "no directory was listed at this path",
+                            )
+                        })?;
+
+                        debug!(line_number, path = %current_path, id, "cd");
+                        current_parent = Some(id);
+                    }
+                    None => {
+                        let inode_id = file_system.new_root_node(INodeDetails::Directory {
+                            path: argument.to_string(),
+                        });
+
+                        current_path = argument.to_string();
+                        debug!(line_number, path = %current_path, id = inode_id, "cd");
+                        current_parent = Some(inode_id);
+                    }
+                },
+                Command::Ls {
+                    output,
+                    line_number,
+                } => {
+                    let parent_id = current_parent.ok_or_else(|| {
+                        ParseError::new(
+                            *line_number,
+                            1,
+                            "$ ls",
+                            "`ls` was used before changing into a directory",
+                        )
+                    })?;
+
+                    debug!(line_number, path = %current_path, entries = output.len(), "ls");
+
+                    let mut all_inode_details = Vec::new();
+
+                    for (i, line) in output.iter().enumerate() {
+                        let split = line.split_whitespace().collect::<Vec<&str>>();
+                        let is_dir = split[0] == "dir";
+
+                        let inode_details = match is_dir {
+                            true => {
+                                let name = split[1];
+                                let path = format!("{}{}/", current_path, name);
+                                INodeDetails::Directory { path }
+                            }
+                            false => {
+                                // The output has already been checked when parsing the commands.
+                                let size = split[0].parse::<u64>().unwrap();
+                                let name = split[1];
+
+                                total_size = total_size.checked_add(size).ok_or_else(|| {
+                                    // The output was gathered from the last line up.
+                                    ParseError::at(
+                                        line_number + output.len() - i,
+                                        line,
+                                        split[0],
+                                        "the files add up to more than a u64 can hold",
+                                    )
+                                })?;
+                                let path = format!("{}{}/", current_path, name);
+
+                                INodeDetails::File { path, size }
+                            }
+                        };
+
+                        all_inode_details.push(inode_details);
+                    }
+
+                    for inode_details in all_inode_details {
+                        file_system.attach_new_node(parent_id, inode_details);
+                    }
+                }
+            };
+        }
+
+        if file_system.inodes.is_empty() {
+            return Err(ParseError::new(
+                1,
+                1,
+                "",
+                "expected the terminal output to start by changing into a directory",
+            ));
+        }
+
+        Ok(file_system)
+    }
+
+    /// Adds the root directory. Only works on an empty file system.
+    pub fn new_root_node(&mut self, data: INodeDetails) -> INodeId {
+        assert_eq!(self.inodes.len(), 0);
+
+        self.inodes.push(INode {
+            parent: None,
+            children: Vec::new(),
+            data,
+        });
+
+        0
+    }
+
+    /// Adds an inode inside of the parent directory.
+    pub fn attach_new_node(&mut self, parent_id: INodeId, data: INodeDetails) -> INodeId {
+        // Returns the next index we can use.
+        let next_id = self.inodes.len();
+        trace!(parent_id, id = next_id, ?data, "attached inode");
+
+        self.inodes.push(INode {
+            data,
+            parent: Some(parent_id),
+            children: Vec::new(),
+        });
+
+        self.inodes[parent_id].children.push(next_id);
+
+        next_id
+    }
+
+    // Will panic if id is out of bounds.
+    /// Looks up an inode by its id.
+    pub fn inode_from_id(&self, id: INodeId) -> &INode {
+        &self.inodes[id]
+    }
+
+    /// Finds the inode at the path. Directory paths end with a `/`.
+    pub fn id_from_path(&self, path_to_be_searched: &str) -> Option<INodeId> {
+        for (i, inode) in self.inodes.iter().enumerate() {
+            match &inode.data {
+                INodeDetails::File { path, .. } => {
+                    if path == path_to_be_searched {
+                        return Some(i);
+                    }
+                }
+                INodeDetails::Directory { path } => {
+                    if path == path_to_be_searched {
+                        return Some(i);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// If the inode is a file, it returns the size.
+    /// If the inode is a directory, it returns the sum of
+    /// all files in the directory (recursively).
+    pub fn size_of_inode(&self, id: INodeId) -> u64 {
+        let mut total_size = 0;
+
+        match self.inode_from_id(id).data {
+            INodeDetails::File { size, .. } => {
+                total_size += size;
+            }
+            INodeDetails::Directory { .. } => {
+                for child in &self.inode_from_id(id).children {
+                    total_size += self.size_of_inode(*child);
+                }
+            }
+        }
+
+        total_size
+    }
+
+    // (recursive)
+    /// The ids of every file below the directory, however deep.
+    pub fn ids_of_all_file_descendants(&self, id: INodeId) -> Vec<INodeId> {
+        let mut ids = Vec::new();
+
+        let original = self.inode_from_id(id);
+        for child in &original.children {
+            let inode = self.inode_from_id(*child);
+            match &inode.data {
+                INodeDetails::File { .. } => ids.push(*child),
+                INodeDetails::Directory { .. } => {
+                    let mut new_ids = self.ids_of_all_file_descendants(*child);
+                    ids.append(&mut new_ids);
+                }
+            }
+        }
+
+        ids
+    }
+
+    //(recursive)
+    /// The ids of every directory below the parent, however deep.
+    pub fn ids_of_all_dirs(&self, parent_id: INodeId) -> Vec<INodeId> {
+        let mut ids = Vec::new();
+
+        for child in &self.inodes[parent_id].children {
+            let inode = self.inode_from_id(*child);
+
+            match &inode.data {
+                INodeDetails::File { .. } => {}
+                INodeDetails::Directory { .. } => {
+                    let mut new_ids = self.ids_of_all_dirs(*child);
+
+                    ids.append(&mut new_ids);
+                    ids.push(*child);
+                }
+            }
+        }
+
+        ids
+    }
+
+    /// Does not include root directory.
+    pub fn dir_size_map(&self) -> HashMap<INodeId, u64> {
+        let mut map = HashMap::new();
+        let mut ids_of_dirs = self.ids_of_all_dirs(0);
+
+        // Add the root as well.
+        ids_of_dirs.push(0);
+
+        for id in ids_of_dirs {
+            let size = self.size_of_inode(id);
+            trace!(id, size, "directory size");
+            map.insert(id, size);
+        }
+
+        map
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        common::assert_answers::<Day7>(EXAMPLE, include_str!("../example_answers.toml"));
+    }
+
+    #[test]
+    fn test_input() {
+        common::assert_answers::<Day7>(Day7::INPUT, Day7::ANSWERS);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let error = Day7::parse("$ cd /\n$ ls\n18446744073709551615 a\n1 b\n").unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(
+                3,
+                1,
+                "18446744073709551615",
+                "the files add up to more than a u64 can hold"
+            )
+        );
+
+        // The biggest disk there can be is still solved.
+        let parsed = Day7::parse("$ cd /\n$ ls\n18446744073709551614 a\n1 b\n").unwrap();
+        assert_eq!(Day7::part_one(&parsed), 0);
+        assert_eq!(Day7::part_two(&parsed), 70_000_000);
+    }
+}
@@ -0,0 +1,170 @@
+// https://adventofcode.com/2022/day/8
+
+use common::{ParseError, Solution};
+use grid::{Direction, Grid, Point};
+
+/// The treetop tree house puzzle.
+pub struct Day8;
+
+/// The heights of the trees.
+pub struct Trees {
+    pub grid: Grid<u8>,
+}
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const INPUT: &'static str = include_str!("../input.txt");
+    const ANSWERS: &'static str = include_str!("../answers.toml");
+
+    type Parsed = Trees;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generate_trees(input)
+    }
+
+    fn part_one(trees: &Self::Parsed) -> Self::PartOne {
+        let mut visible_amount = 0;
+
+        for point in trees.grid.points() {
+            if trees.is_visible(point) {
+                visible_amount += 1;
+            }
+        }
+
+        visible_amount
+    }
+
+    fn part_two(trees: &Self::Parsed) -> Self::PartTwo {
+        let mut highest_scenic_score = 0;
+
+        for point in trees.grid.points() {
+            let score = trees.scenic_score(point);
+            if highest_scenic_score < score {
+                highest_scenic_score = score
+            }
+        }
+
+        highest_scenic_score
+    }
+}
+
+/// Parses one row of tree heights per line.
+pub fn generate_trees(input: &str) -> Result<Trees, ParseError> {
+    let mut rows: Vec<Vec<u8>> = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        let line_number = i + 1;
+        let mut row = Vec::new();
+
+        for (index, char) in line.char_indices() {
+            let height = char.to_digit(10).ok_or_else(|| {
+                let token = &line[index..index + char.len_utf8()];
+                ParseError::at(
+                    line_number,
+                    line,
+                    token,
+                    "expected a tree height from 0 to 9",
+                )
+            })?;
+
+            row.push(height as u8);
+        }
+
+        // Every row has to be as wide as the first one for the trees to make a grid.
+        if let Some(first_row) = rows.first() {
+            if row.len() != first_row.len() {
+                return Err(ParseError::at(
+                    line_number,
+                    line,
+                    line,
+                    format!("expected a row of {} trees", first_row.len()),
+                ));
+            }
+        }
+
+        if row.is_empty() {
+            return Err(ParseError::new(
+                line_number,
+                1,
+                "",
+                "expected a row of trees",
+            ));
+        }
+
+        rows.push(row);
+    }
+
+    match Grid::from_rows(rows) {
+        Some(grid) => Ok(Trees::new(grid)),
+        None => Err(ParseError::new(1, 1, "", "expected a row of trees")),
+    }
+}
+
+impl Trees {
+    /// Wraps a grid of tree heights.
+    pub fn new(grid: Grid<u8>) -> Self {
+        Self { grid }
+    }
+
+    /// Whether the tree can be seen from outside the grid.
+    pub fn is_visible(&self, point: Point) -> bool {
+        let tree = self.grid[point];
+
+        // Trees on the edge have nothing in the way, so they are always visible.
+        Direction::ALL
+            .into_iter()
+            .any(|direction| self.trees_towards(point, direction).all(|x| x < tree))
+    }
+
+    /// The viewing distances in every direction multiplied together.
+    pub fn scenic_score(&self, point: Point) -> u64 {
+        let tree = self.grid[point];
+
+        Direction::ALL
+            .into_iter()
+            .map(|direction| Self::viewable_tree_amount(tree, self.trees_towards(point, direction)))
+            .product()
+    }
+
+    fn viewable_tree_amount(chosen_tree: u8, trees: impl Iterator<Item = u8>) -> u64 {
+        let mut viewable = 0;
+
+        for tree in trees {
+            viewable += 1;
+
+            if chosen_tree <= tree {
+                break;
+            }
+        }
+
+        viewable
+    }
+
+    /// The trees in the direction of the tree, closest first.
+    pub fn trees_towards(
+        &self,
+        point: Point,
+        direction: Direction,
+    ) -> impl Iterator<Item = u8> + '_ {
+        self.grid.ray(point, direction).map(|(_, &tree)| tree)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_example() {
+        common::assert_answers::<Day8>(EXAMPLE, include_str!("../example_answers.toml"));
+    }
+
+    #[test]
+    fn test_input() {
+        common::assert_answers::<Day8>(Day8::INPUT, Day8::ANSWERS);
+    }
+}
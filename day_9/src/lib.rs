@@ -0,0 +1,357 @@
+// https://adventofcode.com/2022/day/9
+
+use std::collections::HashSet;
+
+use common::{parse_number, ParseError, Solution};
+use grid::{Direction, Point};
+use tracing::{debug, debug_span, trace};
+
+/// The rope bridge puzzle.
+pub struct Day9;
+
+/// The most steps the head may take in total. The rope is moved one step
+/// at a time, so more than this would take too long to simulate.
+pub const MAX_STEPS: usize = 1_000_000;
+
+/// Moving the head of the rope a few steps in one direction.
+#[derive(Debug, Clone, Copy)]
+pub struct Movement {
+    pub units: usize,
+    pub direction: Direction,
+}
+
+/// A rope made of knots, the first being the head and the last the tail.
+#[derive(Debug)]
+pub struct World {
+    pub knots: Vec<Point>,
+
+    // Contains all the positions that the tail has visited.
+    pub visited: HashSet<Point>,
+}
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const INPUT: &'static str = include_str!("../input.txt");
+    const ANSWERS: &'static str = include_str!("../answers.toml");
+
+    type Parsed = Vec<Movement>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        generate_movements(input)
+    }
+
+    fn part_one(movements: &Self::Parsed) -> Self::PartOne {
+        let mut world = World::new(2);
+        world.process_movements(movements);
+        world.visited_len()
+    }
+
+    fn part_two(movements: &Self::Parsed) -> Self::PartTwo {
+        let mut world = World::new(10);
+        world.process_movements(movements);
+        world.visited_len()
+    }
+}
+
+/// Parses one movement per line.
+pub fn generate_movements(input: &str) -> Result<Vec<Movement>, ParseError> {
+    let mut steps = 0usize;
+
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, x)| {
+            let line_number = i + 1;
+            let split = x.split_whitespace().collect::<Vec<&str>>();
+
+            if split.len() < 2 {
+                return Err(ParseError::end_of_line(
+                    line_number,
+                    x,
+                    "expected a direction and an amount of steps",
+                ));
+            }
+
+            let units: usize = parse_number(line_number, x, split[1])?;
+
+            steps = steps.saturating_add(units);
+            if steps > MAX_STEPS {
+                return Err(ParseError::at(
+                    line_number,
+                    x,
+                    split[1],
+                    "the movements add up to more than a million steps",
+                ));
+            }
+
+            let direction = match split[0] {
+                "U" => Direction::Up,
+                "R" => Direction::Right,
+                "D" => Direction::Down,
+                "L" => Direction::Left,
+                _ => {
+                    return Err(ParseError::at(
+                        line_number,
+                        x,
+                        split[0],
+                        "expected U, R, D or L",
+                    ))
+                }
+            };
+
+            Ok(Movement { units, direction })
+        })
+        .collect()
+}
+
+impl World {
+    /// Every knot starts at the origin.
+    pub fn new(knot_amount: u64) -> Self {
+        Self {
+            knots: vec![Point::ORIGIN; knot_amount as usize],
+            visited: HashSet::new(),
+        }
+    }
+
+    /// Processes each movement in order.
+    pub fn process_movements(&mut self, movements: &[Movement]) {
+        for movement in movements {
+            self.process_movement(*movement);
+        }
+    }
+
+    /// Moves the head one step at a time, with the rest of the knots following.
+    pub fn process_movement(&mut self, movement: Movement) {
+        let _span = debug_span!(
+            "process_movement",
+            direction = ?movement.direction,
+            units = movement.units
+        )
+        .entered();
+
+        for _ in 0..movement.units {
+            self.knots[0] = self.knots[0].step(movement.direction);
+            trace!(knot = 0, position = ?self.knots[0], "moved knot");
+
+            for knot_index in 1..self.knots.len() {
+                if self.is_knot_touching_owner(knot_index) {
+                    // The knots behind this one can't have moved either.
+                    break;
+                }
+
+                // A knot that falls behind moves one step straight (or diagonally)
+                // towards the knot in front of it.
+                let position_change =
+                    (self.knots[knot_index - 1] - self.knots[knot_index]).signum();
+                self.knots[knot_index] += position_change;
+                trace!(knot = knot_index, position = ?self.knots[knot_index], "moved knot");
+            }
+
+            self.add_tail_position_to_visited();
+        }
+
+        debug!(
+            head = ?self.knots[0],
+            tail = ?self.knots[self.knots.len() - 1],
+            visited = self.visited.len(),
+            "moved rope"
+        );
+    }
+
+    /// Adds visited tail positions to self.visited
+    fn add_tail_position_to_visited(&mut self) {
+        self.visited.insert(*self.knots.iter().last().unwrap());
+    }
+
+    /// The amount of positions the tail has visited.
+    pub fn visited_len(&self) -> usize {
+        self.visited.len()
+    }
+
+    /// Whether the knot is touching the knot in front of it.
+    pub fn is_knot_touching_owner(&self, knot_index: usize) -> bool {
+        if knot_index == 0 {
+            return false;
+        }
+
+        self.knots[knot_index].is_touching(self.knots[knot_index - 1])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn test_position_updating() {
+        let mut world = World::new(2);
+
+        let movements = vec![Movement {
+            units: 5,
+            direction: Direction::Up,
+        }];
+
+        world.process_movements(&movements);
+
+        assert_eq!(world.knots[0], Point { x: 0, y: 5 });
+        assert_eq!(world.knots[1], Point { x: 0, y: 4 });
+
+        let movements = vec![Movement {
+            units: 1,
+            direction: Direction::Right,
+        }];
+
+        world.process_movements(&movements);
+
+        assert_eq!(world.knots[0], Point { x: 1, y: 5 });
+        assert_eq!(world.knots[1], Point { x: 0, y: 4 });
+
+        let movements = vec![Movement {
+            units: 1,
+            direction: Direction::Right,
+        }];
+
+        world.process_movements(&movements);
+
+        assert_eq!(world.knots[0], Point { x: 2, y: 5 });
+        assert_eq!(world.knots[1], Point { x: 1, y: 5 });
+
+        let movements = vec![Movement {
+            units: 1,
+            direction: Direction::Down,
+        }];
+
+        world.process_movements(&movements);
+
+        assert_eq!(world.knots[0], Point { x: 2, y: 4 });
+        assert_eq!(world.knots[1], Point { x: 1, y: 5 });
+
+        let movements = vec![Movement {
+            units: 1,
+            direction: Direction::Down,
+        }];
+
+        world.process_movements(&movements);
+
+        assert_eq!(world.knots[0], Point { x: 2, y: 3 });
+        assert_eq!(world.knots[1], Point { x: 2, y: 4 });
+    }
+
+    #[test]
+    fn test_example() {
+        common::assert_answers::<Day9>(EXAMPLE, include_str!("../example_answers.toml"));
+    }
+
+    #[test]
+    fn test_larger_example() {
+        let parsed = Day9::parse(include_str!("../example_2.txt")).unwrap();
+        assert_eq!(Day9::part_two(&parsed), 36);
+    }
+
+    #[test]
+    fn test_input() {
+        common::assert_answers::<Day9>(Day9::INPUT, Day9::ANSWERS);
+    }
+
+    #[test]
+    fn test_too_many_steps() {
+        assert!(Day9::parse("R 1000000\n").is_ok());
+        assert_eq!(
+            Day9::parse("R 1000000\nU 1\n").unwrap_err(),
+            ParseError::new(
+                2,
+                3,
+                "1",
+                "the movements add up to more than a million steps"
+            )
+        );
+        assert!(Day9::parse("R 18446744073709551615\nL 1\n").is_err());
+    }
+
+    fn movements() -> impl Strategy<Value = Vec<Movement>> {
+        let direction = prop::sample::select(Direction::ALL.to_vec());
+        let movement =
+            (direction, 1..20usize).prop_map(|(direction, units)| Movement { units, direction });
+
+        prop::collection::vec(movement, 0..100)
+    }
+
+    fn movements_text(movements: &[Movement]) -> String {
+        movements
+            .iter()
+            .map(|x| {
+                let direction = match x.direction {
+                    Direction::Up => "U",
+                    Direction::Right => "R",
+                    Direction::Down => "D",
+                    Direction::Left => "L",
+                };
+
+                format!("{direction} {}\n", x.units)
+            })
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn test_knots_keep_touching(movements in movements(), knot_amount in 1..12u64) {
+            let mut world = World::new(knot_amount);
+            let mut head = Point::ORIGIN;
+
+            for movement in &movements {
+                for _ in 0..movement.units {
+                    world.process_movement(Movement { units: 1, ..*movement });
+                    head = head.step(movement.direction);
+
+                    prop_assert_eq!(world.knots[0], head);
+
+                    for knot_index in 1..world.knots.len() {
+                        prop_assert!(world.is_knot_touching_owner(knot_index));
+                    }
+
+                    prop_assert!(world.visited.contains(world.knots.last().unwrap()));
+                }
+            }
+
+            let steps = movements.iter().map(|x| x.units).sum::<usize>();
+            prop_assert!(world.visited_len() <= steps.max(1));
+        }
+
+        #[test]
+        fn test_single_knot_visits_head_path(movements in movements()) {
+            // With a single knot, the head is the tail.
+            let mut world = World::new(1);
+            world.process_movements(&movements);
+
+            let mut head = Point::ORIGIN;
+            let mut head_path = HashSet::new();
+
+            for movement in &movements {
+                for _ in 0..movement.units {
+                    head = head.step(movement.direction);
+                    head_path.insert(head);
+                }
+            }
+
+            prop_assert_eq!(world.visited, head_path);
+        }
+
+        #[test]
+        fn test_parsing_movements(movements in movements()) {
+            let parsed = generate_movements(&movements_text(&movements)).unwrap();
+
+            prop_assert_eq!(parsed.len(), movements.len());
+
+            for (parsed, movement) in parsed.iter().zip(&movements) {
+                prop_assert_eq!(parsed.units, movement.units);
+                prop_assert_eq!(parsed.direction, movement.direction);
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+// AssignmentPair::from_string from day 4.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let line = String::from_utf8_lossy(data);
+    let _ = day_4::AssignmentPair::from_string(1, &line);
+});
@@ -0,0 +1,9 @@
+// generate_instructions from day 10.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let input = String::from_utf8_lossy(data);
+    let _ = day_10::generate_instructions(&input);
+});
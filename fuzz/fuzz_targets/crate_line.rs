@@ -0,0 +1,9 @@
+// Dock::crate_line_to_chars from day 5.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let line = String::from_utf8_lossy(data);
+    let _ = day_5::Dock::crate_line_to_chars(1, &line);
+});
@@ -0,0 +1,9 @@
+// generate_monkeys from day 11.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let input = String::from_utf8_lossy(data);
+    let _ = day_11::generate_monkeys(&input);
+});
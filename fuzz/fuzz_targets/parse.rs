@@ -0,0 +1,36 @@
+// The whole parser of a day, picked by the first byte. Whatever it accepts
+// is solved too, since an input that parses shouldn't make either part panic.
+#![no_main]
+
+use common::{Part, Solution};
+use libfuzzer_sys::fuzz_target;
+
+fn solve<S: Solution>(input: &str) {
+    if let Ok(parsed) = S::parse(input) {
+        for part in Part::BOTH {
+            part.solve::<S>(&parsed);
+        }
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Some((day, input)) = data.split_first() else {
+        return;
+    };
+
+    let input = String::from_utf8_lossy(input);
+
+    match day % 11 + 1 {
+        1 => solve::<day_1::Day1>(&input),
+        2 => solve::<day_2::Day2>(&input),
+        3 => solve::<day_3::Day3>(&input),
+        4 => solve::<day_4::Day4>(&input),
+        5 => solve::<day_5::Day5>(&input),
+        6 => solve::<day_6::Day6>(&input),
+        7 => solve::<day_7::Day7>(&input),
+        8 => solve::<day_8::Day8>(&input),
+        9 => solve::<day_9::Day9>(&input),
+        10 => solve::<day_10::Day10>(&input),
+        _ => solve::<day_11::Day11>(&input),
+    }
+});
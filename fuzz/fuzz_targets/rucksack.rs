@@ -0,0 +1,9 @@
+// Rucksack::from_string from day 3.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let line = String::from_utf8_lossy(data);
+    let _ = day_3::Rucksack::from_string(1, &line);
+});
@@ -0,0 +1,12 @@
+// parse_commands from day 7, and building the file system from what it parsed.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let input = String::from_utf8_lossy(data);
+
+    if let Ok(commands) = day_7::parse_commands(&input) {
+        let _ = day_7::FileSystem::from_commands(&commands);
+    }
+});
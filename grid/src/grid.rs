@@ -0,0 +1,176 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{Direction, Point};
+
+/// A dense rectangle of cells, indexed by [`Point`]s with the origin at the bottom left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// Row by row, starting with the bottom one (y = 0).
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows as they would be read, so the top row comes first.
+    /// Returns `None` if there are no cells, or the rows aren't all as wide.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first()?.len();
+        let height = rows.len();
+
+        if width == 0 || rows.iter().any(|x| x.len() != width) {
+            return None;
+        }
+
+        let cells = rows.into_iter().rev().flatten().collect();
+
+        Some(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether the point is inside the grid.
+    pub fn contains(&self, point: Point) -> bool {
+        (0..self.width as i64).contains(&point.x) && (0..self.height as i64).contains(&point.y)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cell_index(point).map(|x| &self.cells[x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.cell_index(point).map(|x| &mut self.cells[x])
+    }
+
+    /// Every point in the grid, going along the rows from the top left.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width as i64;
+
+        (0..self.height as i64)
+            .rev()
+            .flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// The neighbours of the point (see [`Point::neighbours`]) that are inside the grid.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point.neighbours().filter(|&x| self.contains(x))
+    }
+
+    /// Walks from the point (leaving it out) in the direction until it
+    /// reaches the edge of the grid, closest cell first.
+    pub fn ray(&self, from: Point, direction: Direction) -> impl Iterator<Item = (Point, &T)> {
+        let mut point = from;
+
+        std::iter::from_fn(move || {
+            point = point.step(direction);
+            self.get(point).map(|cell| (point, cell))
+        })
+    }
+
+    /// Draws the grid with one character per cell, top row first.
+    pub fn render(&self, mut cell_char: impl FnMut(&T) -> char) -> String {
+        self.cells
+            .chunks(self.width)
+            .rev()
+            .map(|row| row.iter().map(&mut cell_char).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn cell_index(&self, point: Point) -> Option<usize> {
+        match self.contains(point) {
+            true => Some(point.y as usize * self.width + point.x as usize),
+            false => None,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid with every cell set to `fill`. Returns `None` if there would be
+    /// no cells, like [`Grid::from_rows`].
+    pub fn new(width: usize, height: usize, fill: T) -> Option<Self> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height,
+            cells: vec![fill; width.checked_mul(height)?],
+        })
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        match self.get(point) {
+            Some(cell) => cell,
+            None => panic!("{point:?} is outside of the grid"),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        match self.get_mut(point) {
+            Some(cell) => cell,
+            None => panic!("{point:?} is outside of the grid"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::from_rows(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]).unwrap()
+    }
+
+    #[test]
+    fn test_indexing() {
+        let mut grid = example();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(0, 0)], 'd');
+        assert_eq!(grid[Point::new(2, 1)], 'c');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+
+        grid[Point::new(1, 0)] = 'x';
+        assert_eq!(grid.render(|&x| x), "abc\ndxf");
+
+        assert_eq!(Grid::new(2, 1, '.').unwrap().render(|&x| x), "..");
+        assert_eq!(Grid::new(0, 3, '.'), None);
+        assert_eq!(Grid::new(3, 0, '.'), None);
+        assert_eq!(Grid::<char>::from_rows(vec![]), None);
+        assert_eq!(Grid::from_rows(vec![vec![1], vec![2, 3]]), None);
+    }
+
+    #[test]
+    fn test_walking() {
+        let grid = example();
+
+        assert_eq!(grid.points().map(|x| grid[x]).collect::<String>(), "abcdef");
+        assert_eq!(
+            grid.ray(Point::new(0, 0), Direction::Right)
+                .map(|(_, &x)| x)
+                .collect::<String>(),
+            "ef"
+        );
+        assert_eq!(grid.ray(Point::new(0, 1), Direction::Up).count(), 0);
+        assert_eq!(grid.neighbours(Point::new(0, 0)).count(), 2);
+    }
+}
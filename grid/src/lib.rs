@@ -0,0 +1,12 @@
+// Two dimensional grids, shared by the days that need them.
+//
+// Everything uses the same coordinates: x grows to the right and y grows
+// upwards, so the origin of a grid is its bottom left corner.
+
+mod direction;
+mod grid;
+mod point;
+
+pub use direction::Direction;
+pub use grid::Grid;
+pub use point::Point;
@@ -0,0 +1,117 @@
+use std::ops::{Add, AddAssign, Sub};
+
+use crate::Direction;
+
+/// A signed point, so it can leave any grid it is used with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// The point one step away in the direction.
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+
+    /// The 4 points directly above, right of, below and left of this one.
+    pub fn neighbours(self) -> impl Iterator<Item = Point> {
+        Direction::ALL.into_iter().map(move |x| self.step(x))
+    }
+
+    /// The 8 points around this one, diagonals included.
+    pub fn all_neighbours(self) -> impl Iterator<Item = Point> {
+        (-1..=1)
+            .flat_map(|y| (-1..=1).map(move |x| Point::new(x, y)))
+            .filter(|&offset| offset != Point::ORIGIN)
+            .map(move |offset| self + offset)
+    }
+
+    /// Whether the points are the same, or next to each other (diagonals included).
+    pub fn is_touching(self, other: Point) -> bool {
+        self.chebyshev_distance(other) <= 1
+    }
+
+    /// The amount of king moves (like in chess) between the points.
+    pub fn chebyshev_distance(self, other: Point) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// The amount of steps between the points, without moving diagonally.
+    pub fn manhattan_distance(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Clamps both coordinates to -1, 0 or 1, giving the single (diagonal)
+    /// step that moves closest towards this point from the origin.
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Self::Output {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Self::Output {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_neighbours() {
+        let point = Point::new(2, 3);
+
+        assert_eq!(
+            point.neighbours().collect::<Vec<Point>>(),
+            [
+                Point::new(2, 4),
+                Point::new(3, 3),
+                Point::new(2, 2),
+                Point::new(1, 3)
+            ]
+        );
+
+        let all_neighbours = point.all_neighbours().collect::<Vec<Point>>();
+        assert_eq!(all_neighbours.len(), 8);
+        assert!(all_neighbours
+            .iter()
+            .all(|x| x.is_touching(point) && *x != point));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(-1, 2);
+        let b = Point::new(3, 1);
+
+        assert_eq!(a.chebyshev_distance(b), 4);
+        assert_eq!(a.manhattan_distance(b), 5);
+        assert_eq!((b - a).signum(), Point::new(1, -1));
+        assert!(!a.is_touching(b));
+        assert!(a.is_touching(a));
+    }
+}